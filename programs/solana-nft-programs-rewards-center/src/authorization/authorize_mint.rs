@@ -25,11 +25,13 @@ pub struct AuthorizeMintCtx<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<AuthorizeMintCtx>, mint: Pubkey) -> Result<()> {
+pub fn handler(ctx: Context<AuthorizeMintCtx>, mint: Pubkey, expires_at: Option<i64>, max_stake_seconds: Option<u128>) -> Result<()> {
     let stake_authorization_record = &mut ctx.accounts.stake_authorization_record;
     stake_authorization_record.bump = *ctx.bumps.get("stake_authorization_record").unwrap();
     stake_authorization_record.pool = ctx.accounts.stake_pool.key();
     stake_authorization_record.mint = mint;
+    stake_authorization_record.expires_at = expires_at;
+    stake_authorization_record.max_stake_seconds = max_stake_seconds;
 
     Ok(())
 }
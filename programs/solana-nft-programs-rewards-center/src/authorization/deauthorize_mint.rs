@@ -1,5 +1,8 @@
 use super::StakeAuthorizationRecord;
 use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::mint_stake_entry;
+use crate::stake_entry_fill_zeros;
 use crate::StakePool;
 use anchor_lang::prelude::*;
 
@@ -9,10 +12,26 @@ pub struct DeauthorizeMintCtx<'info> {
     stake_pool: Account<'info, StakePool>,
     #[account(mut, close = authority, constraint = stake_authorization_record.pool == stake_pool.key() @ ErrorCode::InvalidStakeAuthorizationRecord)]
     stake_authorization_record: Account<'info, StakeAuthorizationRecord>,
+    /// CHECK: Checked in handler
+    #[account(mut)]
+    stake_entry: UncheckedAccount<'info>,
     #[account(mut, constraint = authority.key() == stake_pool.authority @ ErrorCode::InvalidAuthority)]
     authority: Signer<'info>,
 }
 
-pub fn handler(_ctx: Context<DeauthorizeMintCtx>) -> Result<()> {
+pub fn handler(ctx: Context<DeauthorizeMintCtx>) -> Result<()> {
+    // revoke an entry that was staked through this record so it stops accruing
+    let stake_authorization_record = &ctx.accounts.stake_authorization_record;
+    if let Some(mut stake_entry) = mint_stake_entry(&ctx.accounts.stake_entry.to_account_info(), ctx.accounts.stake_pool.key(), stake_authorization_record.mint)? {
+        if stake_entry.stake_authorization_record == Some(stake_authorization_record.key()) {
+            if stake_entry.last_staker != Pubkey::default() {
+                increment_total_stake_seconds(&mut stake_entry, &ctx.accounts.stake_pool)?;
+            }
+            stake_entry.authorization_expires_at = Some(Clock::get().unwrap().unix_timestamp);
+            stake_entry.exit(&crate::id())?;
+            stake_entry_fill_zeros(&mut stake_entry)?;
+        }
+    }
+
     Ok(())
 }
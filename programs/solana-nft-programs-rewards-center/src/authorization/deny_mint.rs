@@ -0,0 +1,54 @@
+use super::StakeDenyRecord;
+use super::STAKE_DENY_SEED;
+use super::STAKE_DENY_SIZE;
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::mint_stake_entry;
+use crate::stake_entry_fill_zeros;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct DenyMintCtx<'info> {
+    #[account(mut)]
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(
+        init,
+        payer = payer,
+        space = STAKE_DENY_SIZE,
+        seeds = [STAKE_DENY_SEED.as_bytes(), stake_pool.key().as_ref(), mint.as_ref()],
+        bump
+    )]
+    stake_deny_record: Box<Account<'info, StakeDenyRecord>>,
+    /// CHECK: Checked in handler
+    #[account(mut)]
+    stake_entry: UncheckedAccount<'info>,
+    #[account(mut, constraint = authority.key() == stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<DenyMintCtx>, mint: Pubkey) -> Result<()> {
+    let stake_deny_record = &mut ctx.accounts.stake_deny_record;
+    stake_deny_record.bump = *ctx.bumps.get("stake_deny_record").unwrap();
+    stake_deny_record.pool = ctx.accounts.stake_pool.key();
+    stake_deny_record.mint = mint;
+
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.denied_mints = stake_pool.denied_mints.checked_add(1).expect("Add error");
+
+    // stop accrual for an entry that is already staked
+    if let Some(mut stake_entry) = mint_stake_entry(&ctx.accounts.stake_entry.to_account_info(), stake_pool.key(), mint)? {
+        if stake_entry.last_staker != Pubkey::default() {
+            increment_total_stake_seconds(&mut stake_entry, stake_pool)?;
+        }
+        stake_entry.denied = true;
+        stake_entry.exit(&crate::id())?;
+        stake_entry_fill_zeros(&mut stake_entry)?;
+    }
+
+    Ok(())
+}
@@ -4,5 +4,17 @@ pub use authorize_mint::*;
 pub mod deauthorize_mint;
 pub use deauthorize_mint::*;
 
+pub mod sync_stake_entry_authorization;
+pub use sync_stake_entry_authorization::*;
+
+pub mod deny_mint;
+pub use deny_mint::*;
+
+pub mod undeny_mint;
+pub use undeny_mint::*;
+
+pub mod sync_stake_entry_denied;
+pub use sync_stake_entry_denied::*;
+
 pub mod state;
 pub use state::*;
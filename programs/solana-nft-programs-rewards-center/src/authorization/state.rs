@@ -1,10 +1,20 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 use mpl_token_metadata::accounts::Metadata;
 use mpl_utils::assert_derivation;
+use solana_program::ed25519_program;
+use solana_program::keccak;
+use solana_program::sysvar;
+use solana_program::sysvar::instructions::load_current_index_checked;
+use solana_program::sysvar::instructions::load_instruction_at_checked;
 use std::slice::Iter;
 
 use crate::errors::ErrorCode;
+use crate::utils::merkle_proof_is_valid;
+use crate::Action;
+use crate::StakeEntry;
 use crate::StakePool;
+use crate::STAKE_ENTRY_PREFIX;
 
 pub const STAKE_AUTHORIZATION_SEED: &str = "stake-authorization";
 pub const STAKE_AUTHORIZATION_SIZE: usize = 8 + std::mem::size_of::<StakeAuthorizationRecord>() + 8;
@@ -14,9 +24,93 @@ pub struct StakeAuthorizationRecord {
     pub bump: u8,
     pub pool: Pubkey,
     pub mint: Pubkey,
+    pub expires_at: Option<i64>,
+    pub max_stake_seconds: Option<u128>,
 }
 
-pub fn mint_is_allowed(stake_pool: &Account<StakePool>, stake_mint_metadata: &AccountInfo, stake_mint: Pubkey, remaining_accounts: &mut Iter<AccountInfo>) -> Result<()> {
+// the mint's stake entry must be passed whenever it exists, an empty account is only accepted at its address
+pub fn mint_stake_entry<'info>(stake_entry_info: &AccountInfo<'info>, stake_pool: Pubkey, mint: Pubkey) -> Result<Option<Account<'info, StakeEntry>>> {
+    if stake_entry_info.data_is_empty() {
+        assert_derivation(
+            &crate::id(),
+            stake_entry_info,
+            &[STAKE_ENTRY_PREFIX.as_bytes(), stake_pool.as_ref(), mint.as_ref(), Pubkey::default().as_ref()],
+            error!(ErrorCode::InvalidStakeEntry),
+        )?;
+        return Ok(None);
+    }
+    let stake_entry = match Account::<StakeEntry>::try_from(stake_entry_info) {
+        Ok(stake_entry) => stake_entry,
+        Err(_) => return Err(error!(ErrorCode::InvalidStakeEntry)),
+    };
+    if stake_entry.pool != stake_pool || stake_entry.stake_mint != mint {
+        return Err(error!(ErrorCode::InvalidStakeEntry));
+    }
+    Ok(Some(stake_entry))
+}
+
+pub const STAKE_DENY_SEED: &str = "stake-deny";
+pub const STAKE_DENY_SIZE: usize = 8 + std::mem::size_of::<StakeDenyRecord>() + 8;
+
+#[account]
+pub struct StakeDenyRecord {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+}
+
+// message signed by the pool permit signer and verified through an ed25519 program instruction
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct StakePermit {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub user: Pubkey,
+    pub expires_at: i64,
+    pub nonce: u64,
+}
+
+// size of each Ed25519SignatureOffsets entry following the 2 byte header
+const ED25519_SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct Trait {
+    pub trait_type: String,
+    pub value: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct TraitRule {
+    // all traits must match when set, otherwise any one of them
+    pub match_all: bool,
+    pub traits: Vec<Trait>,
+}
+
+impl TraitRule {
+    pub fn is_satisfied_by(&self, traits: &[Trait]) -> bool {
+        if self.match_all {
+            self.traits.iter().all(|t| traits.contains(t))
+        } else {
+            self.traits.iter().any(|t| traits.contains(t))
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TraitProof {
+    pub traits: Vec<Trait>,
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub fn mint_is_allowed(
+    stake_pool: &Account<StakePool>,
+    stake_entry: &mut Account<StakeEntry>,
+    stake_mint_metadata: &AccountInfo,
+    user: Pubkey,
+    mint_proof: &[[u8; 32]],
+    trait_proof: Option<&TraitProof>,
+    remaining_accounts: &mut Iter<AccountInfo>,
+) -> Result<Option<u64>> {
+    let stake_mint = stake_entry.stake_mint;
     assert_derivation(
         &mpl_token_metadata::ID,
         &stake_mint_metadata.to_account_info(),
@@ -24,7 +118,33 @@ pub fn mint_is_allowed(stake_pool: &Account<StakePool>, stake_mint_metadata: &Ac
         error!(ErrorCode::InvalidMintMetadataOwner),
     )?;
 
-    if !stake_pool.allowed_creators.is_empty() || !stake_pool.allowed_collections.is_empty() || stake_pool.requires_authorization {
+    if stake_pool.denied_mints > 0 {
+        let stake_deny_record_info = next_account_info(remaining_accounts)?;
+        assert_derivation(
+            &crate::id(),
+            stake_deny_record_info,
+            &[STAKE_DENY_SEED.as_bytes(), stake_pool.key().as_ref(), stake_mint.as_ref()],
+            error!(ErrorCode::InvalidStakeDenyRecord),
+        )?;
+        if !stake_deny_record_info.data_is_empty() {
+            return Err(error!(ErrorCode::MintDenied));
+        }
+    }
+    // the mint is no longer denied, resume accrual from now without crediting the denied period
+    if stake_entry.denied {
+        stake_entry.denied = false;
+        stake_entry.last_updated_at = Clock::get().unwrap().unix_timestamp;
+    }
+
+    // authorization terms only apply while staked through a record
+    let previous_stake_authorization_record = stake_entry.stake_authorization_record;
+    stake_entry.stake_authorization_record = None;
+    stake_entry.authorization_expires_at = None;
+    stake_entry.authorization_max_stake_seconds = None;
+
+    // nonce of the permit that allowed the mint, consumed by the caller when staking
+    let mut permit_nonce: Option<u64> = None;
+    if !stake_pool.allowed_creators.is_empty() || !stake_pool.allowed_collections.is_empty() || stake_pool.allowed_mints_root.is_some() || stake_pool.requires_authorization {
         let mut allowed = false;
 
         if !stake_mint_metadata.data_is_empty() {
@@ -53,19 +173,161 @@ pub fn mint_is_allowed(stake_pool: &Account<StakePool>, stake_mint_metadata: &Ac
             }
         }
 
+        if let Some(allowed_mints_root) = stake_pool.allowed_mints_root {
+            if !allowed && merkle_proof_is_valid(mint_proof, allowed_mints_root, keccak::hash(stake_mint.as_ref()).0) {
+                allowed = true;
+            }
+        }
+
         if stake_pool.requires_authorization && !allowed {
             let stake_entry_authorization_info = next_account_info(remaining_accounts)?;
-            let stake_entry_authorization_account = match Account::<StakeAuthorizationRecord>::try_from(stake_entry_authorization_info) {
-                Ok(record) => record,
-                Err(_) => return Err(error!(ErrorCode::InvalidStakeAuthorizationRecord)),
-            };
-            if stake_entry_authorization_account.pool == stake_pool.key() && stake_entry_authorization_account.mint == stake_mint {
-                allowed = true;
+            match stake_pool.permit_signer {
+                Some(permit_signer) if stake_entry_authorization_info.key() == sysvar::instructions::id() => {
+                    let permit = stake_permit_from_instructions(stake_entry_authorization_info, permit_signer, stake_pool.key(), stake_mint, user)?;
+                    if Clock::get().unwrap().unix_timestamp >= permit.expires_at {
+                        return Err(error!(ErrorCode::StakePermitExpired));
+                    }
+                    permit_nonce = Some(permit.nonce);
+                    allowed = true;
+                }
+                _ => {
+                    let stake_entry_authorization_account = match Account::<StakeAuthorizationRecord>::try_from(stake_entry_authorization_info) {
+                        Ok(record) => record,
+                        Err(_) => return Err(error!(ErrorCode::InvalidStakeAuthorizationRecord)),
+                    };
+                    if stake_entry_authorization_account.pool == stake_pool.key() && stake_entry_authorization_account.mint == stake_mint {
+                        if let Some(expires_at) = stake_entry_authorization_account.expires_at {
+                            if Clock::get().unwrap().unix_timestamp >= expires_at {
+                                return Err(error!(ErrorCode::StakeAuthorizationExpired));
+                            }
+                        }
+                        if previous_stake_authorization_record != Some(stake_entry_authorization_account.key()) {
+                            stake_entry.authorization_stake_seconds = 0;
+                        }
+                        stake_entry.stake_authorization_record = Some(stake_entry_authorization_account.key());
+                        stake_entry.authorization_expires_at = stake_entry_authorization_account.expires_at;
+                        stake_entry.authorization_max_stake_seconds = stake_entry_authorization_account.max_stake_seconds;
+                        allowed = true;
+                    }
+                }
             }
         }
         if !allowed {
             return Err(error!(ErrorCode::MintNotAllowedInPool));
         }
     }
+
+    if let Some(trait_rules_root) = stake_pool.trait_rules_root {
+        let trait_proof = match trait_proof {
+            Some(trait_proof) => trait_proof,
+            None => return Err(error!(ErrorCode::InvalidTraitProof)),
+        };
+        assert_trait_proof(trait_rules_root, stake_mint, trait_proof)?;
+        if !stake_pool.trait_rules.is_empty() && !stake_pool.trait_rules.iter().any(|r| r.is_satisfied_by(&trait_proof.traits)) {
+            return Err(error!(ErrorCode::TraitRulesNotSatisfied));
+        }
+    }
+    Ok(permit_nonce)
+}
+
+pub fn assert_trait_proof(trait_rules_root: [u8; 32], stake_mint: Pubkey, trait_proof: &TraitProof) -> Result<()> {
+    let leaf = keccak::hashv(&[stake_mint.as_ref(), &trait_proof.traits.try_to_vec()?]).0;
+    if !merkle_proof_is_valid(&trait_proof.proof, trait_rules_root, leaf) {
+        return Err(error!(ErrorCode::InvalidTraitProof));
+    }
     Ok(())
 }
+
+pub fn stake_permit_from_instructions(instructions_info: &AccountInfo, permit_signer: Pubkey, pool: Pubkey, mint: Pubkey, user: Pubkey) -> Result<StakePermit> {
+    // the ed25519 program fails the transaction on a bad signature so a matching instruction proves the permit
+    let current_index = load_current_index_checked(instructions_info)?;
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(usize::from(index), instructions_info)?;
+        if instruction.program_id != ed25519_program::id() {
+            continue;
+        }
+        if let Some(permit) = parse_ed25519_stake_permit(&instruction.data, permit_signer, pool, mint, user) {
+            return Ok(permit);
+        }
+    }
+    Err(error!(ErrorCode::InvalidStakePermit))
+}
+
+fn parse_ed25519_stake_permit(data: &[u8], permit_signer: Pubkey, pool: Pubkey, mint: Pubkey, user: Pubkey) -> Option<StakePermit> {
+    let read_u16 = |offset: usize| data.get(offset..offset + 2).map(|b| u16::from_le_bytes([b[0], b[1]]));
+    let num_signatures = *data.first()?;
+    for i in 0..usize::from(num_signatures) {
+        let start = 2 + i * ED25519_SIGNATURE_OFFSETS_SIZE;
+        let signature_instruction_index = read_u16(start + 2)?;
+        let public_key_offset = usize::from(read_u16(start + 4)?);
+        let public_key_instruction_index = read_u16(start + 6)?;
+        let message_data_offset = usize::from(read_u16(start + 8)?);
+        let message_data_size = usize::from(read_u16(start + 10)?);
+        let message_instruction_index = read_u16(start + 12)?;
+        // only accept signatures whose data lives in the ed25519 instruction itself
+        if signature_instruction_index != u16::MAX || public_key_instruction_index != u16::MAX || message_instruction_index != u16::MAX {
+            continue;
+        }
+        if data.get(public_key_offset..public_key_offset + 32)? != permit_signer.as_ref() {
+            continue;
+        }
+        let message = data.get(message_data_offset..message_data_offset + message_data_size)?;
+        // other permits may be signed in the same transaction, keep looking for the one for this stake
+        if let Ok(permit) = StakePermit::try_from_slice(message) {
+            if permit.pool == pool && permit.mint == mint && permit.user == user {
+                return Some(permit);
+            }
+        }
+    }
+    None
+}
+
+pub fn assert_holds_pass(pass_mint: Pubkey, holder: Pubkey, remaining_accounts: &mut Iter<AccountInfo>) -> Result<()> {
+    let pass_token_account_info = next_account_info(remaining_accounts)?;
+    let pass_token_account = match Account::<TokenAccount>::try_from(pass_token_account_info) {
+        Ok(token_account) => token_account,
+        Err(_) => return Err(error!(ErrorCode::InvalidPassTokenAccount)),
+    };
+    if pass_token_account.owner != holder || pass_token_account.amount == 0 {
+        return Err(error!(ErrorCode::InvalidPassTokenAccount));
+    }
+    if pass_token_account.mint == pass_mint {
+        return Ok(());
+    }
+
+    // otherwise the pass mint is a collection and the held token must be a verified member of it
+    let pass_mint_metadata_info = next_account_info(remaining_accounts)?;
+    assert_derivation(
+        &mpl_token_metadata::ID,
+        pass_mint_metadata_info,
+        &["metadata".to_string().as_bytes(), mpl_token_metadata::ID.as_ref(), pass_token_account.mint.as_ref()],
+        error!(ErrorCode::InvalidPassMintMetadata),
+    )?;
+    if pass_mint_metadata_info.owner.key() != mpl_token_metadata::ID {
+        return Err(error!(ErrorCode::InvalidPassMintMetadata));
+    }
+    let pass_mint_metadata_data = pass_mint_metadata_info.try_borrow_data().expect("Failed to borrow data");
+    let pass_mint_metadata = Metadata::deserialize(&mut pass_mint_metadata_data.as_ref()).expect("Failed to deserialize metadata");
+    match pass_mint_metadata.collection {
+        Some(collection) if collection.verified && collection.key == pass_mint => Ok(()),
+        _ => Err(error!(ErrorCode::InvalidPassMintMetadata)),
+    }
+}
+
+pub fn is_fee_exempt(stake_pool: &StakePool, action: Action, holder: Pubkey, remaining_accounts: &mut Iter<AccountInfo>) -> Result<bool> {
+    let fee_exempt_mint = match stake_pool.fee_exempt_mint {
+        Some(fee_exempt_mint) => fee_exempt_mint,
+        None => return Ok(false),
+    };
+    if matches!(action, Action::ClaimRewards | Action::BoostStakeEntry) && !stake_pool.fee_exempt_boost_and_claim {
+        return Ok(false);
+    }
+    // the exemption slot always comes before the payment info account, the program id marks it empty
+    let mut exemption_accounts = remaining_accounts.clone();
+    if next_account_info(&mut exemption_accounts)?.key() == crate::id() {
+        *remaining_accounts = exemption_accounts;
+        return Ok(false);
+    }
+    assert_holds_pass(fee_exempt_mint, holder, remaining_accounts)?;
+    Ok(true)
+}
@@ -0,0 +1,34 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SyncStakeEntryAuthorizationCtx<'info> {
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = stake_entry.pool == stake_pool.key() @ ErrorCode::InvalidStakePool)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+    /// CHECK: Checked in handler, empty once the record is deauthorized
+    #[account(constraint = Some(stake_authorization_record.key()) == stake_entry.stake_authorization_record @ ErrorCode::InvalidStakeAuthorizationRecord)]
+    stake_authorization_record: UncheckedAccount<'info>,
+}
+
+// covers entries not passed to deauthorize_mint, such as per-user entries of fungible mints
+pub fn handler(ctx: Context<SyncStakeEntryAuthorizationCtx>) -> Result<()> {
+    if !ctx.accounts.stake_authorization_record.data_is_empty() {
+        return Ok(());
+    }
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    let now = Clock::get().unwrap().unix_timestamp;
+    if stake_entry.authorization_expires_at.is_some() && stake_entry.authorization_expires_at.unwrap() <= now {
+        return Ok(());
+    }
+    if stake_entry.last_staker != Pubkey::default() {
+        increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+    }
+    stake_entry.authorization_expires_at = Some(now);
+    stake_entry_fill_zeros(stake_entry)?;
+    Ok(())
+}
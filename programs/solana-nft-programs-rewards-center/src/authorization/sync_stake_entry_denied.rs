@@ -0,0 +1,39 @@
+use super::STAKE_DENY_SEED;
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+use mpl_utils::assert_derivation;
+
+#[derive(Accounts)]
+pub struct SyncStakeEntryDeniedCtx<'info> {
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = stake_entry.pool == stake_pool.key() @ ErrorCode::InvalidStakePool)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+    /// CHECK: Checked in handler, empty when the mint is not denied
+    stake_deny_record: UncheckedAccount<'info>,
+}
+
+// covers entries not passed to deny_mint or undeny_mint, such as per-user entries of fungible mints
+pub fn handler(ctx: Context<SyncStakeEntryDeniedCtx>) -> Result<()> {
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    assert_derivation(
+        &crate::id(),
+        &ctx.accounts.stake_deny_record.to_account_info(),
+        &[STAKE_DENY_SEED.as_bytes(), ctx.accounts.stake_pool.key().as_ref(), stake_entry.stake_mint.as_ref()],
+        error!(ErrorCode::InvalidStakeDenyRecord),
+    )?;
+
+    let denied = !ctx.accounts.stake_deny_record.data_is_empty();
+    if stake_entry.denied != denied {
+        // credit up to now before denying, or skip the denied period when undenying
+        if stake_entry.last_staker != Pubkey::default() {
+            increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+        }
+        stake_entry.denied = denied;
+        stake_entry_fill_zeros(stake_entry)?;
+    }
+    Ok(())
+}
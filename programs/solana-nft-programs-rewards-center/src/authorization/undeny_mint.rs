@@ -0,0 +1,37 @@
+use super::StakeDenyRecord;
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::mint_stake_entry;
+use crate::stake_entry_fill_zeros;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UndenyMintCtx<'info> {
+    #[account(mut)]
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, close = authority, constraint = stake_deny_record.pool == stake_pool.key() @ ErrorCode::InvalidStakeDenyRecord)]
+    stake_deny_record: Box<Account<'info, StakeDenyRecord>>,
+    /// CHECK: Checked in handler
+    #[account(mut)]
+    stake_entry: UncheckedAccount<'info>,
+    #[account(mut, constraint = authority.key() == stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<UndenyMintCtx>) -> Result<()> {
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.denied_mints = stake_pool.denied_mints.saturating_sub(1);
+
+    // resume accrual from now, the denied period is not credited
+    if let Some(mut stake_entry) = mint_stake_entry(&ctx.accounts.stake_entry.to_account_info(), stake_pool.key(), ctx.accounts.stake_deny_record.mint)? {
+        if stake_entry.last_staker != Pubkey::default() {
+            increment_total_stake_seconds(&mut stake_entry, stake_pool)?;
+        }
+        stake_entry.denied = false;
+        stake_entry.exit(&crate::id())?;
+        stake_entry_fill_zeros(&mut stake_entry)?;
+    }
+
+    Ok(())
+}
@@ -11,6 +11,8 @@ pub enum ErrorCode {
     InvalidAuthority,
     #[msg("Mismatched user and escrow")]
     InvalidEscrow,
+    #[msg("Invalid rent payer")]
+    InvalidRentPayer,
 
     // actions
     #[msg("Invalid user original mint token account")]
@@ -23,6 +25,10 @@ pub enum ErrorCode {
     CannotCloseStakedEntry,
     #[msg("Cannot close staked entry")]
     CannotClosePoolWithStakedEntries,
+    #[msg("Cannot close escrow with outstanding delegations")]
+    CannotCloseEscrowWithDelegations,
+    #[msg("Stake entry must be closed first")]
+    StakeEntryNotClosed,
 
     // authorization errors
     #[msg("Invalid mint metadata")]
@@ -33,6 +39,8 @@ pub enum ErrorCode {
     InvalidStakeAuthorizationRecord,
     #[msg("Mint metadata is owned by the incorrect program")]
     InvalidMintMetadataOwner,
+    #[msg("Stake authorization has expired")]
+    StakeAuthorizationExpired,
 
     // payment errors
     #[msg("Invalid payment mint")]
@@ -51,12 +59,28 @@ pub enum ErrorCode {
     // cooldown errors
     #[msg("Token still has some cooldown seconds remaining")]
     CooldownSecondRemaining = 40,
+    #[msg("Stake entry is not in cooldown")]
+    StakeEntryNotInCooldown,
 
     // stake_pool errors
     #[msg("Stake pool has ended")]
     StakePoolHasEnded = 50,
     #[msg("Minimum stake seconds not satisfied")]
     MinStakeSecondsNotSatisfied,
+    #[msg("Stake pool has reached its maximum staked")]
+    MaxStakedReached,
+    #[msg("User has reached the maximum staked per wallet")]
+    MaxStakedPerWalletReached,
+    #[msg("Invalid lock tier")]
+    InvalidLockTier,
+    #[msg("Stake entry is still locked")]
+    StakeEntryLocked,
+    #[msg("Invalid stake seconds decay")]
+    InvalidStakeSecondsDecay,
+    #[msg("Invalid loyalty ramp")]
+    InvalidLoyaltyRamp,
+    #[msg("Invalid boost window")]
+    InvalidBoostWindow,
 
     // boost errors
     #[msg("Cannot boost unstaked token")]
@@ -125,4 +149,82 @@ pub enum ErrorCode {
     InvalidAuthorityTokenAccount,
     #[msg("Max reward seconds claimed")]
     MaxRewardSecondsClaimed,
+    #[msg("Invalid reward multiplier proof")]
+    InvalidRewardMultiplierProof,
+    #[msg("Invalid reward bonus rule")]
+    InvalidRewardBonusRule,
+    #[msg("Invalid reward bonus evidence")]
+    InvalidRewardBonusEvidence,
+
+    // pass errors
+    #[msg("Invalid pass token account")]
+    InvalidPassTokenAccount = 110,
+    #[msg("Invalid pass mint metadata")]
+    InvalidPassMintMetadata,
+
+    // trait errors
+    #[msg("Invalid trait proof")]
+    InvalidTraitProof = 120,
+    #[msg("Mint traits do not satisfy the pool trait rules")]
+    TraitRulesNotSatisfied,
+
+    // deny errors
+    #[msg("Mint is denied in this pool")]
+    MintDenied = 130,
+    #[msg("Invalid stake deny record")]
+    InvalidStakeDenyRecord,
+
+    // permit errors
+    #[msg("Invalid stake permit")]
+    InvalidStakePermit = 140,
+    #[msg("Stake permit has expired")]
+    StakePermitExpired,
+    #[msg("Stake permit nonce already used")]
+    StakePermitNonceUsed,
+
+    // timed multiplier errors
+    #[msg("Timed multiplier must expire in the future")]
+    InvalidTimedMultiplierExpiry = 150,
+    #[msg("No free timed multiplier slots on stake entry")]
+    TimedMultipliersFull,
+
+    // stake set errors
+    #[msg("Invalid stake set rule")]
+    InvalidStakeSetRule = 160,
+    #[msg("Invalid stake set member")]
+    InvalidStakeSetMember,
+    #[msg("Invalid stake set")]
+    InvalidStakeSet,
+
+    // team errors
+    #[msg("Invalid team")]
+    InvalidTeam = 170,
+    #[msg("Stake entry is already in a team")]
+    StakeEntryAlreadyInTeam,
+    #[msg("Stake entry is not in this team")]
+    StakeEntryNotInTeam,
+    #[msg("Cannot close team with members")]
+    CannotCloseTeamWithMembers,
+
+    // leaderboard errors
+    #[msg("Invalid leaderboard")]
+    InvalidLeaderboard = 180,
+    #[msg("Invalid leaderboard kind")]
+    InvalidLeaderboardKind,
+    #[msg("Invalid leaderboard size")]
+    InvalidLeaderboardSize,
+    #[msg("Leaderboard size exceeds the maximum account size")]
+    LeaderboardSizeTooLarge,
+    #[msg("Stake entry is not in the leaderboard's season")]
+    LeaderboardSeasonMismatch,
+
+    // swap errors
+    #[msg("Stake swaps are disabled for this pool")]
+    StakeSwapDisabled = 190,
+    #[msg("Invalid stake swap")]
+    InvalidStakeSwap,
+    #[msg("Invalid stake swap reward entry")]
+    InvalidStakeSwapRewardEntry,
+    #[msg("Stake swap source entry must be unstaked later in the transaction")]
+    StakeSwapSourceNotUnstaked,
 }
@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+use crate::leaderboard::Leaderboard;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseLeaderboardCtx<'info> {
+    #[account(mut, close = authority, constraint = leaderboard.pool == stake_pool.key() @ ErrorCode::InvalidLeaderboard)]
+    leaderboard: Box<Account<'info, Leaderboard>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+
+    #[account(mut, constraint = authority.key() == stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseLeaderboardCtx>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::errors::ErrorCode;
+use crate::leaderboard::Leaderboard;
+use crate::leaderboard::LeaderboardKind;
+use crate::leaderboard::LEADERBOARD_DEFAULT_SIZE;
+use crate::leaderboard::LEADERBOARD_ENTRY_SIZE;
+use crate::leaderboard::LEADERBOARD_PREFIX;
+use crate::leaderboard::MAX_LEADERBOARD_SIZE;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitLeaderboardIx {
+    pub identifier: u64,
+    pub kind: u8,
+    pub reward_distributor: Option<Pubkey>,
+    pub size: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: InitLeaderboardIx)]
+pub struct InitLeaderboardCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = LEADERBOARD_DEFAULT_SIZE + usize::from(ix.size.min(MAX_LEADERBOARD_SIZE)) * LEADERBOARD_ENTRY_SIZE,
+        seeds = [LEADERBOARD_PREFIX.as_bytes(), stake_pool.key().as_ref(), ix.identifier.to_le_bytes().as_ref()],
+        bump,
+    )]
+    leaderboard: Box<Account<'info, Leaderboard>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+
+    #[account(constraint = authority.key() == stake_pool.authority @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitLeaderboardCtx>, ix: InitLeaderboardIx) -> Result<()> {
+    if ix.size == 0 {
+        return Err(error!(ErrorCode::InvalidLeaderboardSize));
+    }
+    if ix.size > MAX_LEADERBOARD_SIZE {
+        return Err(error!(ErrorCode::LeaderboardSizeTooLarge));
+    }
+    let reward_seconds_kind = ix.kind == LeaderboardKind::RewardSecondsReceived as u8;
+    if !(ix.kind == LeaderboardKind::TotalStakeSeconds as u8 || reward_seconds_kind) || reward_seconds_kind != ix.reward_distributor.is_some() {
+        return Err(error!(ErrorCode::InvalidLeaderboardKind));
+    }
+
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.bump = *ctx.bumps.get("leaderboard").unwrap();
+    leaderboard.pool = ctx.accounts.stake_pool.key();
+    leaderboard.identifier = ix.identifier;
+    leaderboard.kind = ix.kind;
+    leaderboard.reward_distributor = ix.reward_distributor;
+    leaderboard.size = ix.size;
+    leaderboard.entries = Vec::new();
+    leaderboard.season = ctx.accounts.stake_pool.season;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+pub mod state;
+pub use state::*;
+
+pub mod init_leaderboard;
+pub use init_leaderboard::*;
+
+pub mod update_leaderboard;
+pub use update_leaderboard::*;
+
+pub mod close_leaderboard;
+pub use close_leaderboard::*;
@@ -0,0 +1,86 @@
+use crate::errors::ErrorCode;
+use crate::RewardEntry;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum LeaderboardKind {
+    TotalStakeSeconds = 0,
+    RewardSecondsReceived,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub stake_entry: Pubkey,
+    pub user: Pubkey,
+    pub value: u128,
+}
+
+pub const LEADERBOARD_PREFIX: &str = "leaderboard";
+pub const LEADERBOARD_ENTRY_SIZE: usize = 32 + 32 + 16;
+pub const LEADERBOARD_DEFAULT_SIZE: usize = 8 + 1 + 32 + 8 + 1 + 33 + 1 + 4 + 4;
+// largest size whose account fits in the 10240 bytes allowed when creating an account
+pub const MAX_LEADERBOARD_SIZE: u8 = ((10240 - LEADERBOARD_DEFAULT_SIZE) / LEADERBOARD_ENTRY_SIZE) as u8;
+#[account]
+pub struct Leaderboard {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub identifier: u64,
+    pub kind: u8,
+    pub reward_distributor: Option<Pubkey>,
+    pub size: u8,
+    // sorted by value descending, ties ordered by stake entry key
+    pub entries: Vec<LeaderboardEntry>,
+    // entries only rank the pool's current season
+    pub season: u32,
+}
+
+pub fn leaderboard_update(leaderboard: &mut Leaderboard, stake_pool: &StakePool, stake_entry: &Account<StakeEntry>, reward_entry: Option<&Account<RewardEntry>>) -> Result<()> {
+    if leaderboard.pool != stake_entry.pool {
+        return Err(error!(ErrorCode::InvalidLeaderboard));
+    }
+    //// FEATURE: Seasons
+    if leaderboard.season != stake_pool.season {
+        // a new season starts with an empty leaderboard
+        leaderboard.entries.clear();
+        leaderboard.season = stake_pool.season;
+    }
+    if stake_entry.season != leaderboard.season {
+        return Err(error!(ErrorCode::LeaderboardSeasonMismatch));
+    }
+    let value = match leaderboard.kind {
+        k if k == LeaderboardKind::TotalStakeSeconds as u8 => stake_entry.total_stake_seconds,
+        k if k == LeaderboardKind::RewardSecondsReceived as u8 => match reward_entry {
+            Some(reward_entry) if Some(reward_entry.reward_distributor) == leaderboard.reward_distributor && reward_entry.stake_entry == stake_entry.key() => reward_entry.reward_seconds_received,
+            // nothing to rank without the matching reward entry
+            _ => return Ok(()),
+        },
+        _ => return Err(error!(ErrorCode::InvalidLeaderboardKind)),
+    };
+
+    leaderboard.entries.retain(|e| e.stake_entry != stake_entry.key());
+    if value == 0 {
+        return Ok(());
+    }
+    let position = leaderboard
+        .entries
+        .iter()
+        .position(|e| e.value < value || (e.value == value && e.stake_entry > stake_entry.key()))
+        .unwrap_or(leaderboard.entries.len());
+    if position >= usize::from(leaderboard.size) {
+        return Ok(());
+    }
+    leaderboard.entries.insert(
+        position,
+        LeaderboardEntry {
+            stake_entry: stake_entry.key(),
+            user: stake_entry.last_staker,
+            value,
+        },
+    );
+    // evict the lowest ranked entry once full
+    leaderboard.entries.truncate(usize::from(leaderboard.size));
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use crate::errors::ErrorCode;
+use crate::leaderboard::leaderboard_update;
+use crate::leaderboard::Leaderboard;
+use crate::RewardEntry;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateLeaderboardCtx<'info> {
+    #[account(mut, constraint = leaderboard.pool == stake_pool.key() @ ErrorCode::InvalidLeaderboard)]
+    leaderboard: Box<Account<'info, Leaderboard>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+    stake_entry: Box<Account<'info, StakeEntry>>,
+    reward_entry: Option<Box<Account<'info, RewardEntry>>>,
+}
+
+pub fn handler(ctx: Context<UpdateLeaderboardCtx>) -> Result<()> {
+    // entries from an earlier season are rejected until update_total_stake_seconds rolls them over
+    leaderboard_update(&mut ctx.accounts.leaderboard, &ctx.accounts.stake_pool, &ctx.accounts.stake_entry, ctx.accounts.reward_entry.as_deref())
+}
@@ -12,6 +12,12 @@ pub mod reward_receipts;
 pub use reward_receipts::*;
 pub mod payment;
 pub use payment::*;
+pub mod stake_set;
+pub use stake_set::*;
+pub mod team;
+pub use team::*;
+pub mod leaderboard;
+pub use leaderboard::*;
 
 pub mod errors;
 pub mod utils;
@@ -32,13 +38,20 @@ pub mod solana_nft_programs_rewards_center {
     pub fn update_pool(ctx: Context<UpdatePoolCtx>, ix: UpdatePoolIx) -> Result<()> {
         stake_pool::update_pool::handler(ctx, ix)
     }
+    pub fn migrate_stake_pool(ctx: Context<MigrateStakePoolCtx>) -> Result<()> {
+        stake_pool::migrate_stake_pool::handler(ctx)
+    }
+    pub fn advance_season(ctx: Context<AdvanceSeasonCtx>) -> Result<()> {
+        stake_pool::advance_season::handler(ctx)
+    }
+
     pub fn close_stake_pool(ctx: Context<CloseStakePoolCtx>) -> Result<()> {
         stake_pool::close_stake_pool::handler(ctx)
     }
 
     //// stake_entry ////
-    pub fn init_entry(ctx: Context<InitEntryCtx>, user: Pubkey) -> Result<()> {
-        stake_entry::init_entry::handler(ctx, user)
+    pub fn init_entry(ctx: Context<InitEntryCtx>, user: Pubkey, mint_proof: Vec<[u8; 32]>, trait_proof: Option<TraitProof>) -> Result<()> {
+        stake_entry::init_entry::handler(ctx, user, mint_proof, trait_proof)
     }
     pub fn update_total_stake_seconds(ctx: Context<UpdateTotalStakeSecondsCtx>) -> Result<()> {
         stake_entry::update_total_stake_seconds::handler(ctx)
@@ -58,41 +71,113 @@ pub mod solana_nft_programs_rewards_center {
     pub fn decrement_stake_entry_multiplier_stake_seconds(ctx: Context<DecrementStakeEntryMultiplierStakeSecondsCtx>, multiplier_stake_seconds: u128) -> Result<()> {
         stake_entry::multiplier_stake_seconds::decrement_stake_entry_multiplier_stake_seconds::handler(ctx, multiplier_stake_seconds)
     }
+    pub fn add_stake_entry_timed_multiplier(ctx: Context<AddStakeEntryTimedMultiplierCtx>, multiplier_basis_points: u64, expires_at: i64) -> Result<()> {
+        stake_entry::multiplier_stake_seconds::add_stake_entry_timed_multiplier::handler(ctx, multiplier_basis_points, expires_at)
+    }
     pub fn resize_stake_entry(ctx: Context<ResizeStakeEntryCtx>) -> Result<()> {
         stake_entry::resize_stake_entry::handler(ctx)
     }
     pub fn close_stake_entry(ctx: Context<CloseStakeEntryCtx>) -> Result<()> {
         stake_entry::close_stake_entry::handler(ctx)
     }
+    pub fn close_user_escrow(ctx: Context<CloseUserEscrowCtx>) -> Result<()> {
+        stake_entry::close_user_escrow::handler(ctx)
+    }
+    pub fn cancel_cooldown(ctx: Context<CancelCooldownCtx>) -> Result<()> {
+        stake_entry::cancel_cooldown::handler(ctx)
+    }
+    pub fn swap_stake(ctx: Context<SwapStakeCtx>) -> Result<()> {
+        stake_entry::swap_stake::handler(ctx)
+    }
+    pub fn backfill_user_stake_registry(ctx: Context<BackfillUserStakeRegistryCtx>) -> Result<()> {
+        stake_entry::backfill_user_stake_registry::handler(ctx)
+    }
     //// stake_entry::editions ////
-    pub fn stake_edition<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, StakeEditionCtx<'info>>, amount: u64) -> Result<()> {
-        stake_entry::editions::stake_edition::handler(ctx, amount)
+    pub fn stake_edition<'key, 'accounts, 'remaining, 'info>(
+        ctx: Context<'key, 'accounts, 'remaining, 'info, StakeEditionCtx<'info>>,
+        amount: u64,
+        mint_proof: Vec<[u8; 32]>,
+        trait_proof: Option<TraitProof>,
+        lock_tier: Option<u8>,
+    ) -> Result<()> {
+        stake_entry::editions::stake_edition::handler(ctx, amount, mint_proof, trait_proof, lock_tier)
     }
     pub fn unstake_edition<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UnstakeEditionCtx<'info>>) -> Result<()> {
         stake_entry::editions::unstake_edition::handler(ctx)
     }
     //// stake_entry::ccs ////
-    pub fn stake_ccs<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, StakeCCSCtx<'info>>, amount: u64) -> Result<()> {
-        stake_entry::ccs::stake_ccs::handler(ctx, amount)
+    pub fn stake_ccs<'key, 'accounts, 'remaining, 'info>(
+        ctx: Context<'key, 'accounts, 'remaining, 'info, StakeCCSCtx<'info>>,
+        amount: u64,
+        mint_proof: Vec<[u8; 32]>,
+        trait_proof: Option<TraitProof>,
+        lock_tier: Option<u8>,
+    ) -> Result<()> {
+        stake_entry::ccs::stake_ccs::handler(ctx, amount, mint_proof, trait_proof, lock_tier)
     }
     pub fn unstake_ccs<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UnstakeCCSCtx<'info>>) -> Result<()> {
         stake_entry::ccs::unstake_ccs::handler(ctx)
     }
-    pub fn stake_pnft(ctx: Context<StakePNFTCtx>) -> Result<()> {
-        stake_entry::pnfts::stake_pnft::handler(ctx)
+    pub fn stake_pnft(ctx: Context<StakePNFTCtx>, mint_proof: Vec<[u8; 32]>, trait_proof: Option<TraitProof>, lock_tier: Option<u8>) -> Result<()> {
+        stake_entry::pnfts::stake_pnft::handler(ctx, mint_proof, trait_proof, lock_tier)
     }
 
-    pub fn unstake_pnft(ctx: Context<UnstakePNFTCtx>) -> Result<()> {
+    pub fn unstake_pnft<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UnstakePNFTCtx<'info>>) -> Result<()> {
         stake_entry::pnfts::unstake_pnft::handler(ctx)
     }
 
+    //// stake_set ////
+    pub fn form_stake_set(ctx: Context<FormStakeSetCtx>, ix: FormStakeSetIx) -> Result<()> {
+        stake_set::form_stake_set::handler(ctx, ix)
+    }
+
+    //// team ////
+    pub fn init_team(ctx: Context<InitTeamCtx>, identifier: u64) -> Result<()> {
+        team::init_team::handler(ctx, identifier)
+    }
+    pub fn join_team(ctx: Context<JoinTeamCtx>) -> Result<()> {
+        team::join_team::handler(ctx)
+    }
+    pub fn leave_team(ctx: Context<LeaveTeamCtx>) -> Result<()> {
+        team::leave_team::handler(ctx)
+    }
+    pub fn sync_team_stake_seconds(ctx: Context<SyncTeamStakeSecondsCtx>) -> Result<()> {
+        team::sync_team_stake_seconds::handler(ctx)
+    }
+    pub fn close_team(ctx: Context<CloseTeamCtx>) -> Result<()> {
+        team::close_team::handler(ctx)
+    }
+
+    //// leaderboard ////
+    pub fn init_leaderboard(ctx: Context<InitLeaderboardCtx>, ix: InitLeaderboardIx) -> Result<()> {
+        leaderboard::init_leaderboard::handler(ctx, ix)
+    }
+    pub fn update_leaderboard(ctx: Context<UpdateLeaderboardCtx>) -> Result<()> {
+        leaderboard::update_leaderboard::handler(ctx)
+    }
+    pub fn close_leaderboard(ctx: Context<CloseLeaderboardCtx>) -> Result<()> {
+        leaderboard::close_leaderboard::handler(ctx)
+    }
+
     //// authorization ////
-    pub fn authorize_mint(ctx: Context<AuthorizeMintCtx>, mint: Pubkey) -> Result<()> {
-        authorization::authorize_mint::handler(ctx, mint)
+    pub fn authorize_mint(ctx: Context<AuthorizeMintCtx>, mint: Pubkey, expires_at: Option<i64>, max_stake_seconds: Option<u128>) -> Result<()> {
+        authorization::authorize_mint::handler(ctx, mint, expires_at, max_stake_seconds)
     }
     pub fn deauthorize_mint(ctx: Context<DeauthorizeMintCtx>) -> Result<()> {
         authorization::deauthorize_mint::handler(ctx)
     }
+    pub fn sync_stake_entry_authorization(ctx: Context<SyncStakeEntryAuthorizationCtx>) -> Result<()> {
+        authorization::sync_stake_entry_authorization::handler(ctx)
+    }
+    pub fn deny_mint(ctx: Context<DenyMintCtx>, mint: Pubkey) -> Result<()> {
+        authorization::deny_mint::handler(ctx, mint)
+    }
+    pub fn undeny_mint(ctx: Context<UndenyMintCtx>) -> Result<()> {
+        authorization::undeny_mint::handler(ctx)
+    }
+    pub fn sync_stake_entry_denied(ctx: Context<SyncStakeEntryDeniedCtx>) -> Result<()> {
+        authorization::sync_stake_entry_denied::handler(ctx)
+    }
 
     //// stake_booster ////
     pub fn init_stake_booster(ctx: Context<InitStakeBoosterCtx>, ix: InitStakeBoosterIx) -> Result<()> {
@@ -149,6 +234,14 @@ pub mod solana_nft_programs_rewards_center {
         reward_distribution::reward_distributor::reclaim_funds::handler(ctx, amount)
     }
 
+    //// reward_distribution::reward_bonus_rule ////
+    pub fn init_reward_bonus_rule(ctx: Context<InitRewardBonusRuleCtx>, ix: InitRewardBonusRuleIx) -> Result<()> {
+        reward_distribution::reward_bonus_rule::init_reward_bonus_rule::handler(ctx, ix)
+    }
+    pub fn close_reward_bonus_rule(ctx: Context<CloseRewardBonusRuleCtx>) -> Result<()> {
+        reward_distribution::reward_bonus_rule::close_reward_bonus_rule::handler(ctx)
+    }
+
     //// reward_distribution::reward_entry ////
     pub fn init_reward_entry(ctx: Context<InitRewardEntryCtx>) -> Result<()> {
         reward_distribution::reward_entry::init_reward_entry::handler(ctx)
@@ -159,6 +252,9 @@ pub mod solana_nft_programs_rewards_center {
     pub fn update_reward_entry(ctx: Context<UpdateRewardEntryCtx>, ix: UpdateRewardEntryIx) -> Result<()> {
         reward_distribution::reward_entry::update_reward_entry::handler(ctx, ix)
     }
+    pub fn sync_reward_entry_multiplier(ctx: Context<SyncRewardEntryMultiplierCtx>, ix: SyncRewardEntryMultiplierIx) -> Result<()> {
+        reward_distribution::reward_entry::sync_reward_entry_multiplier::handler(ctx, ix)
+    }
     pub fn claim_rewards(ctx: Context<ClaimRewardsCtx>) -> Result<()> {
         reward_distribution::reward_entry::claim_rewards::handler(ctx)
     }
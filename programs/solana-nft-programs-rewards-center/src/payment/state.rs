@@ -1,4 +1,5 @@
 use crate::errors::ErrorCode;
+use crate::RewardDistributor;
 use anchor_lang::prelude::*;
 use anchor_lang::Result;
 use anchor_spl::token;
@@ -136,19 +137,34 @@ pub fn handle_payment<'info>(payment_amount: u64, payment_mint: Pubkey, payment_
                     )?;
                 }
             } else {
-                // any spl token
-                let payment_share_token_account = Account::<TokenAccount>::try_from(payment_share_account_info)?;
+                // any spl token, or the treasury of a reward distributor paying out this mint
+                let mut reward_distributor: Option<Account<RewardDistributor>> = None;
+                let payment_share_token_account_info = if payment_share_account_info.key() == payment_share.address && payment_share_account_info.owner.key() == crate::id() {
+                    let reward_distributor_data = Account::<RewardDistributor>::try_from(payment_share_account_info)?;
+                    if reward_distributor_data.reward_mint != payment_mint.key() {
+                        return Err(error!(ErrorCode::InvalidRewardMint));
+                    }
+                    reward_distributor = Some(reward_distributor_data);
+                    next_account_info(remaining_accounts)?
+                } else {
+                    payment_share_account_info
+                };
+                let payment_share_token_account = Account::<TokenAccount>::try_from(payment_share_token_account_info)?;
                 if payment_share_token_account.owner != payment_share.address || payment_share_token_account.mint != payment_mint.key() {
                     return Err(error!(ErrorCode::InvalidTokenAccount));
                 }
                 if payment_share_amount > 0 {
                     let cpi_accounts = Transfer {
                         from: payer_token_account.clone().expect("Invalid payer token account").to_account_info(),
-                        to: payment_share_account_info.to_account_info(),
+                        to: payment_share_token_account_info.to_account_info(),
                         authority: payer.to_account_info(),
                     };
                     let cpi_context = CpiContext::new(transfer_program.to_account_info(), cpi_accounts);
                     token::transfer(cpi_context, payment_share_amount)?;
+                    if let Some(mut reward_distributor) = reward_distributor {
+                        reward_distributor.rewards_funded = reward_distributor.rewards_funded.checked_add(u128::from(payment_share_amount)).expect("Add error");
+                        reward_distributor.exit(&crate::id())?;
+                    }
                 }
             }
         }
@@ -7,8 +7,13 @@ pub use reward_distributor::init_reward_distributor::*;
 pub use reward_distributor::reclaim_funds::*;
 pub use reward_distributor::update_reward_distributor::*;
 
+pub mod reward_bonus_rule;
+pub use reward_bonus_rule::close_reward_bonus_rule::*;
+pub use reward_bonus_rule::init_reward_bonus_rule::*;
+
 pub mod reward_entry;
 pub use reward_entry::claim_rewards::*;
 pub use reward_entry::close_reward_entry::*;
 pub use reward_entry::init_reward_entry::*;
+pub use reward_entry::sync_reward_entry_multiplier::*;
 pub use reward_entry::update_reward_entry::*;
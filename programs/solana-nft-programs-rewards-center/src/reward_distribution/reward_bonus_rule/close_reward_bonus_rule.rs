@@ -0,0 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::reward_distribution::RewardBonusRule;
+use crate::reward_distribution::RewardDistributor;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseRewardBonusRuleCtx<'info> {
+    reward_distributor: Box<Account<'info, RewardDistributor>>,
+    #[account(mut, close = authority, constraint = reward_bonus_rule.reward_distributor == reward_distributor.key() @ ErrorCode::InvalidRewardBonusRule)]
+    reward_bonus_rule: Box<Account<'info, RewardBonusRule>>,
+    #[account(mut, constraint = reward_distributor.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseRewardBonusRuleCtx>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::errors::ErrorCode;
+use crate::reward_distribution::RewardBonusRule;
+use crate::reward_distribution::RewardDistributor;
+use crate::reward_distribution::REWARD_BONUS_RULE_SEED;
+use crate::reward_distribution::REWARD_BONUS_RULE_SIZE;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitRewardBonusRuleIx {
+    pub collection: Pubkey,
+    pub multiplier: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: InitRewardBonusRuleIx)]
+pub struct InitRewardBonusRuleCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = REWARD_BONUS_RULE_SIZE,
+        seeds = [REWARD_BONUS_RULE_SEED.as_bytes(), reward_distributor.key().as_ref(), ix.collection.as_ref()],
+        bump,
+    )]
+    reward_bonus_rule: Box<Account<'info, RewardBonusRule>>,
+    reward_distributor: Box<Account<'info, RewardDistributor>>,
+    #[account(constraint = authority.key() == reward_distributor.authority @ ErrorCode::InvalidRewardDistributorAuthority)]
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitRewardBonusRuleCtx>, ix: InitRewardBonusRuleIx) -> Result<()> {
+    let reward_bonus_rule = &mut ctx.accounts.reward_bonus_rule;
+    reward_bonus_rule.bump = *ctx.bumps.get("reward_bonus_rule").unwrap();
+    reward_bonus_rule.reward_distributor = ctx.accounts.reward_distributor.key();
+    reward_bonus_rule.collection = ix.collection;
+    reward_bonus_rule.multiplier = ix.multiplier;
+    Ok(())
+}
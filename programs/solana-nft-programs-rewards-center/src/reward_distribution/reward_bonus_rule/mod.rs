@@ -0,0 +1,5 @@
+pub mod init_reward_bonus_rule;
+pub use init_reward_bonus_rule::*;
+
+pub mod close_reward_bonus_rule;
+pub use close_reward_bonus_rule::*;
@@ -12,6 +12,7 @@ pub struct UpdateRewardDistributorIx {
     pub reward_duration_seconds: u128,
     pub max_reward_seconds_received: Option<u128>,
     pub claim_rewards_payment_info: Pubkey,
+    pub multiplier_root: Option<[u8; 32]>,
 }
 
 #[derive(Accounts)]
@@ -31,6 +32,7 @@ pub fn handler(ctx: Context<UpdateRewardDistributorCtx>, ix: UpdateRewardDistrib
     reward_distributor.reward_duration_seconds = ix.reward_duration_seconds;
     reward_distributor.max_reward_seconds_received = ix.max_reward_seconds_received;
     reward_distributor.claim_rewards_payment_info = ix.claim_rewards_payment_info;
+    reward_distributor.multiplier_root = ix.multiplier_root;
 
     assert_payment_info(reward_distributor.stake_pool, Action::ClaimRewards, ix.claim_rewards_payment_info)?;
     Ok(())
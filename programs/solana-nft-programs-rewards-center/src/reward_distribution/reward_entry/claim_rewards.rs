@@ -1,10 +1,17 @@
 use crate::assert_payment_info;
 use crate::errors::ErrorCode;
 use crate::handle_payment_info;
+use crate::is_fee_exempt;
+use crate::leaderboard_update;
+use crate::reward_distribution::holding_bonus_multiplier;
+use crate::reward_distribution::sync_reward_entry_checkpoints;
 use crate::reward_distribution::RewardDistributor;
 use crate::reward_distribution::RewardEntry;
 use crate::reward_distribution::REWARD_DISTRIBUTOR_SEED;
+use crate::roll_stake_entry_season;
+use crate::stake_entry_fill_zeros;
 use crate::Action;
+use crate::Leaderboard;
 use crate::StakeEntry;
 use crate::StakePool;
 use anchor_lang::prelude::*;
@@ -21,7 +28,7 @@ pub struct ClaimRewardsCtx<'info> {
     #[account(mut, constraint = reward_distributor.stake_pool == stake_pool.key())]
     reward_distributor: Box<Account<'info, RewardDistributor>>,
 
-    #[account(constraint = stake_entry.key() == reward_entry.stake_entry @ ErrorCode::InvalidStakeEntry)]
+    #[account(mut, constraint = stake_entry.key() == reward_entry.stake_entry @ ErrorCode::InvalidStakeEntry)]
     stake_entry: Box<Account<'info, StakeEntry>>,
     #[account(constraint = stake_pool.key() == stake_entry.pool)]
     stake_pool: Box<Account<'info, StakePool>>,
@@ -38,6 +45,8 @@ pub struct ClaimRewardsCtx<'info> {
     user: Signer<'info>,
     token_program: Program<'info, Token>,
     system_program: Program<'info, System>,
+    #[account(mut, constraint = leaderboard.pool == stake_pool.key() @ ErrorCode::InvalidLeaderboard)]
+    leaderboard: Option<Box<Account<'info, Leaderboard>>>,
 }
 
 pub fn handler(ctx: Context<ClaimRewardsCtx>) -> Result<()> {
@@ -49,6 +58,22 @@ pub fn handler(ctx: Context<ClaimRewardsCtx>) -> Result<()> {
     let reward_distributor_seed = &[REWARD_DISTRIBUTOR_SEED.as_bytes(), stake_pool.as_ref(), identifier_seed.as_ref(), &[reward_distributor.bump]];
     let reward_distributor_signer = &[&reward_distributor_seed[..]];
 
+    if stake_entry.denied {
+        return Err(error!(ErrorCode::MintDenied));
+    }
+
+    //// FEATURE: Seasons
+    roll_stake_entry_season(stake_entry, &ctx.accounts.stake_pool)?;
+    stake_entry_fill_zeros(stake_entry)?;
+    sync_reward_entry_checkpoints(reward_entry, stake_entry);
+
+    //// FEATURE: Holding bonuses
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let multiplier = reward_entry
+        .multiplier
+        .checked_add(holding_bonus_multiplier(reward_distributor.key(), stake_entry, remaining_accounts)?)
+        .expect("Add error");
+
     let reward_amount = reward_distributor.reward_amount;
     let reward_duration_seconds = reward_distributor.reward_duration_seconds;
 
@@ -70,7 +95,7 @@ pub fn handler(ctx: Context<ClaimRewardsCtx>) -> Result<()> {
             .unwrap()
             .checked_mul(reward_amount as u128)
             .unwrap()
-            .checked_mul(reward_entry.multiplier as u128)
+            .checked_mul(multiplier as u128)
             .unwrap()
             .checked_div((10_u128).checked_pow(reward_distributor.multiplier_decimals as u32).unwrap())
             .unwrap();
@@ -92,11 +117,11 @@ pub fn handler(ctx: Context<ClaimRewardsCtx>) -> Result<()> {
         // update values
         // this is nuanced about if the rewards are closed, should they get the reward time for that time even though they didnt get any rewards?
         // this only matters if the reward distributor becomes open again and they missed out on some rewards they coudlve gotten
-        let reward_time_to_receive = if reward_entry.multiplier != 0 {
+        let reward_time_to_receive = if multiplier != 0 {
             reward_amount_to_receive
                 .checked_mul((10_u128).checked_pow(reward_distributor.multiplier_decimals as u32).unwrap())
                 .unwrap()
-                .checked_div(reward_entry.multiplier as u128)
+                .checked_div(multiplier as u128)
                 .unwrap()
                 .checked_div(reward_amount as u128)
                 .unwrap()
@@ -110,9 +135,18 @@ pub fn handler(ctx: Context<ClaimRewardsCtx>) -> Result<()> {
         reward_entry.reward_seconds_received = reward_entry.reward_seconds_received.checked_add(reward_time_to_receive).unwrap();
 
         // handle payment
-        let remaining_accounts = &mut ctx.remaining_accounts.iter();
+        // persist and reload the distributor in case the payment shares route fees back into it
+        reward_distributor.exit(&crate::id())?;
         assert_payment_info(stake_pool.key(), Action::ClaimRewards, reward_distributor.claim_rewards_payment_info)?;
-        handle_payment_info(reward_distributor.claim_rewards_payment_info, remaining_accounts)?;
+        if !is_fee_exempt(&ctx.accounts.stake_pool, Action::ClaimRewards, stake_entry.last_staker, remaining_accounts)? {
+            handle_payment_info(reward_distributor.claim_rewards_payment_info, remaining_accounts)?;
+        }
+        reward_distributor.reload()?;
+    }
+
+    //// FEATURE: Leaderboard
+    if let Some(leaderboard) = &mut ctx.accounts.leaderboard {
+        leaderboard_update(leaderboard, &ctx.accounts.stake_pool, stake_entry, Some(reward_entry))?;
     }
 
     Ok(())
@@ -1,17 +1,29 @@
 use crate::errors::ErrorCode;
 use crate::reward_distribution::RewardDistributor;
 use crate::reward_distribution::RewardEntry;
+use crate::utils::rent_payer_or_authority;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct CloseRewardEntryCtx<'info> {
     reward_distributor: Box<Account<'info, RewardDistributor>>,
-    #[account(mut, close = authority, constraint = reward_entry.reward_distributor == reward_distributor.key() @ ErrorCode::InvalidRewardDistributor)]
+    #[account(mut, close = rent_payer, constraint = reward_entry.reward_distributor == reward_distributor.key() @ ErrorCode::InvalidRewardDistributor)]
     reward_entry: Box<Account<'info, RewardEntry>>,
-    #[account(mut, constraint = reward_distributor.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    /// CHECK: Checked in constraint
+    #[account(constraint = stake_entry.key() == reward_entry.stake_entry @ ErrorCode::InvalidStakeEntry)]
+    stake_entry: UncheckedAccount<'info>,
+    /// CHECK: Checked in constraint
+    #[account(mut, constraint = rent_payer.key() == rent_payer_or_authority(reward_entry.rent_payer, reward_distributor.authority) @ ErrorCode::InvalidRentPayer)]
+    rent_payer: UncheckedAccount<'info>,
+    #[account(mut, constraint = reward_distributor.authority == authority.key() || rent_payer.key() == authority.key() @ ErrorCode::InvalidAuthority)]
     authority: Signer<'info>,
 }
 
-pub fn handler(_ctx: Context<CloseRewardEntryCtx>) -> Result<()> {
+pub fn handler(ctx: Context<CloseRewardEntryCtx>) -> Result<()> {
+    // a reopened reward entry starts from zero seconds received, so the rent payer
+    // can only close it once its stake entry is gone
+    if ctx.accounts.authority.key() != ctx.accounts.reward_distributor.authority && !ctx.accounts.stake_entry.data_is_empty() {
+        return Err(error!(ErrorCode::StakeEntryNotClosed));
+    }
     Ok(())
 }
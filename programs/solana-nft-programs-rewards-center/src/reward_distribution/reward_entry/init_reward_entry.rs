@@ -30,7 +30,11 @@ pub fn handler(ctx: Context<InitRewardEntryCtx>) -> Result<()> {
     reward_entry.bump = *ctx.bumps.get("reward_entry").unwrap();
     reward_entry.reward_distributor = reward_distributor.key();
     reward_entry.stake_entry = ctx.accounts.stake_entry.key();
-    reward_entry.reward_seconds_received = 0;
+    // seconds swapped in this season may already have been claimed on the entry they came from
+    reward_entry.reward_seconds_received = ctx.accounts.stake_entry.swapped_in_stake_seconds;
     reward_entry.multiplier = ctx.accounts.reward_distributor.default_multiplier;
+    reward_entry.rent_payer = ctx.accounts.payer.key();
+    reward_entry.season = ctx.accounts.stake_entry.season;
+    reward_entry.swapped_in_stake_seconds = ctx.accounts.stake_entry.swapped_in_stake_seconds;
     Ok(())
 }
@@ -4,6 +4,9 @@ pub use init_reward_entry::*;
 pub mod update_reward_entry;
 pub use update_reward_entry::*;
 
+pub mod sync_reward_entry_multiplier;
+pub use sync_reward_entry_multiplier::*;
+
 pub mod close_reward_entry;
 pub use close_reward_entry::*;
 
@@ -0,0 +1,38 @@
+use crate::errors::ErrorCode;
+use crate::reward_distribution::RewardDistributor;
+use crate::reward_distribution::RewardEntry;
+use crate::utils::merkle_proof_is_valid;
+use crate::StakeEntry;
+use anchor_lang::prelude::*;
+use solana_program::keccak;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SyncRewardEntryMultiplierIx {
+    pub multiplier: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: SyncRewardEntryMultiplierIx)]
+pub struct SyncRewardEntryMultiplierCtx<'info> {
+    #[account(mut, constraint = reward_entry.reward_distributor == reward_distributor.key() @ ErrorCode::InvalidRewardDistributor)]
+    reward_entry: Box<Account<'info, RewardEntry>>,
+    reward_distributor: Box<Account<'info, RewardDistributor>>,
+    #[account(constraint = stake_entry.key() == reward_entry.stake_entry @ ErrorCode::InvalidStakeEntry)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+}
+
+pub fn handler(ctx: Context<SyncRewardEntryMultiplierCtx>, ix: SyncRewardEntryMultiplierIx) -> Result<()> {
+    let multiplier_root = match ctx.accounts.reward_distributor.multiplier_root {
+        Some(multiplier_root) => multiplier_root,
+        None => return Err(error!(ErrorCode::InvalidRewardMultiplierProof)),
+    };
+    let leaf = keccak::hashv(&[ctx.accounts.stake_entry.stake_mint.as_ref(), &ix.multiplier.to_le_bytes()]).0;
+    if !merkle_proof_is_valid(&ix.proof, multiplier_root, leaf) {
+        return Err(error!(ErrorCode::InvalidRewardMultiplierProof));
+    }
+
+    let reward_entry = &mut ctx.accounts.reward_entry;
+    reward_entry.multiplier = ix.multiplier;
+    Ok(())
+}
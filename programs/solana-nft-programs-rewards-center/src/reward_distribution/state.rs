@@ -1,4 +1,10 @@
+use crate::errors::ErrorCode;
+use crate::StakeEntry;
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_utils::assert_derivation;
+use std::slice::Iter;
 
 pub const REWARD_ENTRY_SEED: &str = "reward-entry";
 pub const REWARD_ENTRY_SIZE: usize = 8 + std::mem::size_of::<RewardEntry>() + 64;
@@ -9,6 +15,9 @@ pub struct RewardEntry {
     pub reward_distributor: Pubkey,
     pub reward_seconds_received: u128,
     pub multiplier: u64,
+    pub rent_payer: Pubkey,
+    pub season: u32,
+    pub swapped_in_stake_seconds: u128,
 }
 
 pub const REWARD_DISTRIBUTOR_SEED: &str = "reward-distributor";
@@ -28,4 +37,104 @@ pub struct RewardDistributor {
     pub multiplier_decimals: u8,
     pub claim_rewards_payment_info: Pubkey,
     pub max_reward_seconds_received: Option<u128>,
+    pub multiplier_root: Option<[u8; 32]>,
+    // tokens paid in by payment shares; distributors created before this was tracked read
+    // zero from their padding and count from their first routed fee
+    pub rewards_funded: u128,
+}
+
+pub const REWARD_BONUS_RULE_SEED: &str = "reward-bonus-rule";
+pub const REWARD_BONUS_RULE_SIZE: usize = 8 + std::mem::size_of::<RewardBonusRule>() + 64;
+#[account]
+pub struct RewardBonusRule {
+    pub bump: u8,
+    pub reward_distributor: Pubkey,
+    pub collection: Pubkey,
+    // added to the reward entry multiplier, scaled by multiplier_decimals
+    pub multiplier: u64,
+}
+
+pub fn sync_reward_entry_checkpoints(reward_entry: &mut RewardEntry, stake_entry: &StakeEntry) {
+    //// FEATURE: Seasons
+    if reward_entry.season != stake_entry.season {
+        // reward seconds are counted against the current season's stake seconds
+        reward_entry.reward_seconds_received = 0;
+        reward_entry.season = stake_entry.season;
+        // the stake entry's count restarts with the season, so all of it is new to this entry
+        reward_entry.swapped_in_stake_seconds = 0;
+    }
+    //// FEATURE: Stake swaps
+    // seconds swapped in without migrating this entry may already have been claimed on the other entry
+    let swapped_in_stake_seconds = stake_entry.swapped_in_stake_seconds.saturating_sub(reward_entry.swapped_in_stake_seconds);
+    reward_entry.reward_seconds_received = reward_entry.reward_seconds_received.saturating_add(swapped_in_stake_seconds);
+    reward_entry.swapped_in_stake_seconds = stake_entry.swapped_in_stake_seconds;
+}
+
+pub fn holding_bonus_multiplier(reward_distributor: Pubkey, stake_entry: &StakeEntry, remaining_accounts: &mut Iter<AccountInfo>) -> Result<u64> {
+    let mut bonus_multiplier: u64 = 0;
+    let mut applied_rules: Vec<Pubkey> = Vec::new();
+    // bonus rules and their evidence are optional and come before the payment accounts
+    while let Some(account_info) = remaining_accounts.clone().next() {
+        if account_info.owner.key() != crate::id() {
+            break;
+        }
+        let reward_bonus_rule = match Account::<RewardBonusRule>::try_from(account_info) {
+            Ok(reward_bonus_rule) => reward_bonus_rule,
+            Err(_) => break,
+        };
+        remaining_accounts.next();
+        if reward_bonus_rule.reward_distributor != reward_distributor || applied_rules.contains(&reward_bonus_rule.key()) {
+            return Err(error!(ErrorCode::InvalidRewardBonusRule));
+        }
+        applied_rules.push(reward_bonus_rule.key());
+
+        assert_holds_collection(reward_bonus_rule.collection, stake_entry, remaining_accounts)?;
+        bonus_multiplier = bonus_multiplier.checked_add(reward_bonus_rule.multiplier).expect("Add error");
+    }
+    Ok(bonus_multiplier)
+}
+
+fn assert_holds_collection(collection: Pubkey, stake_entry: &StakeEntry, remaining_accounts: &mut Iter<AccountInfo>) -> Result<()> {
+    // evidence is either a token account held by the staker or another of their stake entries
+    let evidence_info = next_account_info(remaining_accounts)?;
+    let held_mint = if evidence_info.owner.key() == crate::id() {
+        let evidence_stake_entry = match Account::<StakeEntry>::try_from(evidence_info) {
+            Ok(evidence_stake_entry) => evidence_stake_entry,
+            Err(_) => return Err(error!(ErrorCode::InvalidRewardBonusEvidence)),
+        };
+        if evidence_stake_entry.last_staker != stake_entry.last_staker || evidence_stake_entry.amount == 0 {
+            return Err(error!(ErrorCode::InvalidRewardBonusEvidence));
+        }
+        evidence_stake_entry.stake_mint
+    } else {
+        let evidence_token_account = match Account::<TokenAccount>::try_from(evidence_info) {
+            Ok(evidence_token_account) => evidence_token_account,
+            Err(_) => return Err(error!(ErrorCode::InvalidRewardBonusEvidence)),
+        };
+        if evidence_token_account.owner != stake_entry.last_staker || evidence_token_account.amount == 0 {
+            return Err(error!(ErrorCode::InvalidRewardBonusEvidence));
+        }
+        evidence_token_account.mint
+    };
+    // the staked mint being claimed for does not count as also holding
+    if held_mint == stake_entry.stake_mint {
+        return Err(error!(ErrorCode::InvalidRewardBonusEvidence));
+    }
+
+    let held_mint_metadata_info = next_account_info(remaining_accounts)?;
+    assert_derivation(
+        &mpl_token_metadata::ID,
+        held_mint_metadata_info,
+        &["metadata".to_string().as_bytes(), mpl_token_metadata::ID.as_ref(), held_mint.as_ref()],
+        error!(ErrorCode::InvalidRewardBonusEvidence),
+    )?;
+    if held_mint_metadata_info.owner.key() != mpl_token_metadata::ID {
+        return Err(error!(ErrorCode::InvalidRewardBonusEvidence));
+    }
+    let held_mint_metadata_data = held_mint_metadata_info.try_borrow_data().expect("Failed to borrow data");
+    let held_mint_metadata = Metadata::deserialize(&mut held_mint_metadata_data.as_ref()).expect("Failed to deserialize metadata");
+    match held_mint_metadata.collection {
+        Some(held_collection) if held_collection.verified && held_collection.key == collection => Ok(()),
+        _ => Err(error!(ErrorCode::InvalidRewardBonusEvidence)),
+    }
 }
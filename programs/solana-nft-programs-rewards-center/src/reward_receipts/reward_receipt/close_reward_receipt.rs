@@ -1,17 +1,29 @@
 use crate::errors::ErrorCode;
 use crate::reward_receipts::ReceiptManager;
 use crate::reward_receipts::RewardReceipt;
+use crate::utils::rent_payer_or_authority;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct CloseRewardReceiptCtx<'info> {
-    #[account(mut, close = authority, constraint = reward_receipt.receipt_manager == receipt_manager.key() @ ErrorCode::InvalidRewardReceipt)]
+    #[account(mut, close = rent_payer, constraint = reward_receipt.receipt_manager == receipt_manager.key() @ ErrorCode::InvalidRewardReceipt)]
     reward_receipt: Box<Account<'info, RewardReceipt>>,
     receipt_manager: Box<Account<'info, ReceiptManager>>,
-    #[account(mut, constraint = receipt_manager.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    /// CHECK: Checked in constraint
+    #[account(constraint = stake_entry.key() == reward_receipt.stake_entry @ ErrorCode::InvalidStakeEntry)]
+    stake_entry: UncheckedAccount<'info>,
+    /// CHECK: Checked in constraint
+    #[account(mut, constraint = rent_payer.key() == rent_payer_or_authority(reward_receipt.rent_payer, receipt_manager.authority) @ ErrorCode::InvalidRentPayer)]
+    rent_payer: UncheckedAccount<'info>,
+    #[account(mut, constraint = receipt_manager.authority == authority.key() || rent_payer.key() == authority.key() @ ErrorCode::InvalidAuthority)]
     authority: Signer<'info>,
 }
 
-pub fn handler(_ctx: Context<CloseRewardReceiptCtx>) -> Result<()> {
+pub fn handler(ctx: Context<CloseRewardReceiptCtx>) -> Result<()> {
+    // a reopened receipt is unclaimed and allowed again, so the rent payer
+    // can only close it once its stake entry is gone
+    if ctx.accounts.authority.key() != ctx.accounts.receipt_manager.authority && !ctx.accounts.stake_entry.data_is_empty() {
+        return Err(error!(ErrorCode::StakeEntryNotClosed));
+    }
     Ok(())
 }
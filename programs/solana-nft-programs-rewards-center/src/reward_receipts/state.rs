@@ -30,4 +30,5 @@ pub struct RewardReceipt {
     pub receipt_manager: Pubkey,
     pub target: Pubkey,
     pub allowed: bool,
+    pub rent_payer: Pubkey,
 }
@@ -3,6 +3,7 @@ use crate::assert_payment_info;
 use crate::errors::ErrorCode;
 use crate::handle_payment;
 use crate::handle_payment_info;
+use crate::is_fee_exempt;
 use crate::Action;
 use crate::StakeEntry;
 use crate::StakePool;
@@ -67,6 +68,8 @@ pub fn handler(ctx: Context<BoostStakeEntryCtx>, ix: BoostStakeEntryIx) -> Resul
         Action::BoostStakeEntry,
         ctx.accounts.stake_booster.boost_action_payment_info,
     )?;
-    handle_payment_info(ctx.accounts.stake_booster.boost_action_payment_info, remaining_accounts)?;
+    if !is_fee_exempt(&ctx.accounts.stake_pool, Action::BoostStakeEntry, stake_entry.last_staker, remaining_accounts)? {
+        handle_payment_info(ctx.accounts.stake_booster.boost_action_payment_info, remaining_accounts)?;
+    }
     Ok(())
 }
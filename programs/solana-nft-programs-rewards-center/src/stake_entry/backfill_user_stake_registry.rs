@@ -0,0 +1,40 @@
+use crate::errors::ErrorCode;
+use crate::resize_user_stake_registry;
+use crate::user_stake_registry_stake;
+use crate::StakeEntry;
+use crate::StakePool;
+use crate::UserStakeRegistry;
+use crate::USER_STAKE_REGISTRY_DEFAULT_SIZE;
+use crate::USER_STAKE_REGISTRY_PREFIX;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BackfillUserStakeRegistryCtx<'info> {
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(constraint = stake_entry.pool == stake_pool.key() && stake_entry.amount > 0 @ ErrorCode::InvalidStakeEntry)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = USER_STAKE_REGISTRY_DEFAULT_SIZE,
+        seeds = [USER_STAKE_REGISTRY_PREFIX.as_bytes(), stake_pool.key().as_ref(), stake_entry.last_staker.as_ref()],
+        bump,
+    )]
+    user_stake_registry: Box<Account<'info, UserStakeRegistry>>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+// records an entry staked before the registry existed so it counts towards the wallet's limits
+pub fn handler(ctx: Context<BackfillUserStakeRegistryCtx>) -> Result<()> {
+    let stake_entry = &ctx.accounts.stake_entry;
+    let user_stake_registry = &mut ctx.accounts.user_stake_registry;
+    user_stake_registry.bump = *ctx.bumps.get("user_stake_registry").unwrap();
+    user_stake_registry.pool = ctx.accounts.stake_pool.key();
+    user_stake_registry.user = stake_entry.last_staker;
+    user_stake_registry_stake(user_stake_registry, stake_entry);
+    resize_user_stake_registry(user_stake_registry, &ctx.accounts.payer.to_account_info(), &ctx.accounts.system_program.to_account_info())?;
+    Ok(())
+}
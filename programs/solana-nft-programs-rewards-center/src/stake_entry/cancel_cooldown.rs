@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelCooldownCtx<'info> {
+    #[account(constraint = stake_pool.key() == stake_entry.pool @ ErrorCode::InvalidStakePool)]
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+    #[account(constraint = user.key() == stake_entry.last_staker @ ErrorCode::InvalidLastStaker)]
+    user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelCooldownCtx>) -> Result<()> {
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    if stake_entry.cooldown_start_seconds.is_none() {
+        return Err(error!(ErrorCode::StakeEntryNotInCooldown));
+    }
+
+    // the cooldown period is only credited when the pool accrues during cooldown
+    increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+    stake_entry.cooldown_start_seconds = None;
+    stake_entry_fill_zeros(stake_entry)?;
+    Ok(())
+}
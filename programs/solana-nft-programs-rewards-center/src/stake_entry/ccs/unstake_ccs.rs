@@ -1,12 +1,22 @@
 use crate::assert_payment_info;
+use crate::dissolve_stake_set;
 use crate::errors::ErrorCode;
 use crate::escrow_seeds;
 use crate::handle_payment_info;
+use crate::is_fee_exempt;
+use crate::leaderboard_update;
+use crate::resize_user_stake_registry;
 use crate::stake_entry::increment_total_stake_seconds;
 use crate::stake_entry_fill_zeros;
+use crate::team::defer_team_stake_seconds;
+use crate::user_stake_registry_unstake;
 use crate::Action;
+use crate::Leaderboard;
 use crate::StakeEntry;
 use crate::StakePool;
+use crate::UserStakeRegistry;
+use crate::USER_STAKE_REGISTRY_DEFAULT_SIZE;
+use crate::USER_STAKE_REGISTRY_PREFIX;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use anchor_spl::token::Token;
@@ -35,6 +45,14 @@ pub struct UnstakeCCSCtx<'info> {
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut)]
     user_escrow: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = USER_STAKE_REGISTRY_DEFAULT_SIZE,
+        seeds = [USER_STAKE_REGISTRY_PREFIX.as_bytes(), stake_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    user_stake_registry: Box<Account<'info, UserStakeRegistry>>,
     #[account(mut, constraint =
         user_stake_mint_token_account.amount > 0
         && user_stake_mint_token_account.mint == stake_entry.stake_mint
@@ -48,6 +66,8 @@ pub struct UnstakeCCSCtx<'info> {
     creator_standard_program: UncheckedAccount<'info>,
     token_program: Program<'info, Token>,
     system_program: Program<'info, System>,
+    #[account(mut, constraint = leaderboard.pool == stake_pool.key() @ ErrorCode::InvalidLeaderboard)]
+    leaderboard: Option<Box<Account<'info, Leaderboard>>>,
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, UnstakeCCSCtx<'info>>) -> Result<()> {
@@ -66,6 +86,15 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
         return Err(error!(ErrorCode::MinStakeSecondsNotSatisfied));
     }
 
+    //// FEATURE: Lock tiers
+    if stake_entry.unlock_at.is_some() && Clock::get().unwrap().unix_timestamp < stake_entry.unlock_at.unwrap() {
+        return Err(error!(ErrorCode::StakeEntryLocked));
+    }
+
+    //// FEATURE: Stake sets
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    dissolve_stake_set(stake_entry, stake_pool, remaining_accounts, ctx.accounts.user.to_account_info())?;
+
     //// FEATURE: Cooldown
     if stake_pool.cooldown_seconds.is_some() && stake_pool.cooldown_seconds.unwrap() > 0 {
         if stake_entry.cooldown_start_seconds.is_none() {
@@ -101,18 +130,40 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
     )?;
 
     // handle payment
-    let remaining_accounts = &mut ctx.remaining_accounts.iter();
     assert_payment_info(stake_pool.key(), Action::Unstake, stake_pool.unstake_payment_info)?;
-    handle_payment_info(stake_pool.unstake_payment_info, remaining_accounts)?;
+    if !is_fee_exempt(stake_pool, Action::Unstake, ctx.accounts.user.key(), remaining_accounts)? {
+        handle_payment_info(stake_pool.unstake_payment_info, remaining_accounts)?;
+    }
+
+    increment_total_stake_seconds(stake_entry, stake_pool)?;
+
+    //// FEATURE: User stake registry
+    let user_stake_registry = &mut ctx.accounts.user_stake_registry;
+    user_stake_registry.bump = *ctx.bumps.get("user_stake_registry").unwrap();
+    user_stake_registry.pool = stake_pool.key();
+    user_stake_registry.user = ctx.accounts.user.key();
+    user_stake_registry_unstake(user_stake_registry, stake_entry);
+    resize_user_stake_registry(user_stake_registry, &ctx.accounts.user.to_account_info(), &ctx.accounts.system_program.to_account_info())?;
+
+    //// FEATURE: Leaderboard
+    if let Some(leaderboard) = &mut ctx.accounts.leaderboard {
+        leaderboard_update(leaderboard, stake_pool, stake_entry, None)?;
+    }
 
-    increment_total_stake_seconds(stake_entry)?;
     stake_entry.last_staker = Pubkey::default();
     stake_entry.amount = 0;
     stake_entry.cooldown_start_seconds = None;
+    stake_entry.unlock_at = None;
+    stake_entry.lock_multiplier_basis_points = None;
+    stake_entry.loyalty_ramp = None;
+    stake_entry.unstaked_at = Some(Clock::get().unwrap().unix_timestamp);
+    stake_entry.warmup_ends_at = None;
     stake_pool.total_staked = stake_pool.total_staked.checked_sub(1).expect("Sub error");
     if stake_pool.reset_on_unstake {
+        defer_team_stake_seconds(stake_entry);
         stake_entry.total_stake_seconds = 0;
         stake_entry.multiplier_stake_seconds = None;
+        stake_entry.swapped_in_stake_seconds = 0;
     }
     stake_entry_fill_zeros(stake_entry)?;
 
@@ -1,5 +1,6 @@
 use crate::errors::ErrorCode;
 
+use crate::utils::rent_payer_or_authority;
 use crate::StakeEntry;
 use crate::StakePool;
 use anchor_lang::prelude::*;
@@ -10,7 +11,10 @@ pub struct CloseStakeEntryCtx<'info> {
     stake_pool: Box<Account<'info, StakePool>>,
     #[account(mut, constraint = stake_pool.key() == stake_entry.pool @ ErrorCode::InvalidStakePool)]
     stake_entry: Box<Account<'info, StakeEntry>>,
-    #[account(mut, constraint = stake_pool.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    /// CHECK: Checked in constraint
+    #[account(mut, constraint = rent_payer.key() == rent_payer_or_authority(stake_entry.rent_payer, stake_pool.authority) @ ErrorCode::InvalidRentPayer)]
+    rent_payer: UncheckedAccount<'info>,
+    #[account(mut, constraint = stake_pool.authority == authority.key() || rent_payer.key() == authority.key() @ ErrorCode::InvalidAuthority)]
     authority: Signer<'info>,
 }
 
@@ -19,7 +23,7 @@ pub fn handler(ctx: Context<CloseStakeEntryCtx>) -> Result<()> {
     if stake_entry.last_staker != Pubkey::default() {
         return Err(error!(ErrorCode::CannotCloseStakedEntry));
     }
-    ctx.accounts.stake_entry.close(ctx.accounts.authority.to_account_info())?;
+    ctx.accounts.stake_entry.close(ctx.accounts.rent_payer.to_account_info())?;
 
     Ok(())
 }
@@ -0,0 +1,24 @@
+use crate::errors::ErrorCode;
+use crate::UserEscrow;
+use crate::USER_ESCROW_PREFIX;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+
+#[derive(Accounts)]
+pub struct CloseUserEscrowCtx<'info> {
+    #[account(mut, seeds = [USER_ESCROW_PREFIX.as_bytes(), user.key().as_ref()], bump)]
+    user_escrow: Box<Account<'info, UserEscrow>>,
+    #[account(mut)]
+    user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseUserEscrowCtx>) -> Result<()> {
+    // escrows that delegated before delegations were tracked read zero here, which is safe
+    // because unstake_pnft recreates the escrow if it no longer exists
+    if ctx.accounts.user_escrow.delegations != 0 {
+        return Err(error!(ErrorCode::CannotCloseEscrowWithDelegations));
+    }
+    ctx.accounts.user_escrow.close(ctx.accounts.user.to_account_info())?;
+
+    Ok(())
+}
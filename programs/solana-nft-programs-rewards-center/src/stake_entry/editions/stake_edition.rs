@@ -1,15 +1,26 @@
 use crate::assert_payment_info;
+use crate::authorization::assert_holds_pass;
+use crate::authorization::is_fee_exempt;
 use crate::authorization::mint_is_allowed;
+use crate::decay_stake_seconds;
 use crate::errors::ErrorCode;
 use crate::escrow_seeds;
 use crate::handle_payment_info;
+use crate::resize_user_stake_registry;
+use crate::roll_stake_entry_season;
 use crate::stake_entry::increment_total_stake_seconds;
 use crate::stake_entry_fill_zeros;
 use crate::stake_seed;
+use crate::user_stake_registry_stake;
+use crate::user_stake_registry_use_permit;
 use crate::Action;
 use crate::StakeEntry;
 use crate::StakePool;
+use crate::TraitProof;
+use crate::UserStakeRegistry;
 use crate::STAKE_ENTRY_PREFIX;
+use crate::USER_STAKE_REGISTRY_DEFAULT_SIZE;
+use crate::USER_STAKE_REGISTRY_PREFIX;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Approve;
 use anchor_spl::token::Mint;
@@ -17,6 +28,7 @@ use anchor_spl::token::Token;
 use anchor_spl::token::TokenAccount;
 use anchor_spl::token::{self};
 use solana_program::program::invoke_signed;
+use std::cmp::max;
 
 #[derive(Accounts)]
 pub struct StakeEditionCtx<'info> {
@@ -37,6 +49,14 @@ pub struct StakeEditionCtx<'info> {
     /// CHECK: Checked in handler
     #[account(mut)]
     user_escrow: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = USER_STAKE_REGISTRY_DEFAULT_SIZE,
+        seeds = [USER_STAKE_REGISTRY_PREFIX.as_bytes(), stake_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    user_stake_registry: Box<Account<'info, UserStakeRegistry>>,
     #[account(mut, constraint =
         user_stake_mint_token_account.amount > 0
         && user_stake_mint_token_account.mint == stake_entry.stake_mint
@@ -52,7 +72,13 @@ pub struct StakeEditionCtx<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, StakeEditionCtx<'info>>, amount: u64) -> Result<()> {
+pub fn handler<'key, 'accounts, 'remaining, 'info>(
+    ctx: Context<'key, 'accounts, 'remaining, 'info, StakeEditionCtx<'info>>,
+    amount: u64,
+    mint_proof: Vec<[u8; 32]>,
+    trait_proof: Option<TraitProof>,
+    lock_tier: Option<u8>,
+) -> Result<()> {
     let stake_pool = &mut ctx.accounts.stake_pool;
     let stake_entry = &mut ctx.accounts.stake_entry;
 
@@ -67,7 +93,32 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
 
     //// FEATURE: Allowlist
     let remaining_accounts = &mut ctx.remaining_accounts.iter();
-    mint_is_allowed(stake_pool, &ctx.accounts.stake_mint_metadata, ctx.accounts.stake_mint.key(), remaining_accounts)?;
+    let permit_nonce = mint_is_allowed(
+        stake_pool,
+        stake_entry,
+        &ctx.accounts.stake_mint_metadata,
+        ctx.accounts.user.key(),
+        &mint_proof,
+        trait_proof.as_ref(),
+        remaining_accounts,
+    )?;
+    user_stake_registry_use_permit(&mut ctx.accounts.user_stake_registry, permit_nonce)?;
+
+    //// FEATURE: Pass
+    if let Some(pass_mint) = stake_pool.pass_mint {
+        assert_holds_pass(pass_mint, ctx.accounts.user.key(), remaining_accounts)?;
+    }
+
+    //// FEATURE: Max staked
+    // both limits count staked entries, topping up a staked fungible entry does not add one
+    if stake_entry.amount == 0 {
+        if stake_pool.max_staked.is_some() && stake_pool.total_staked >= stake_pool.max_staked.unwrap() {
+            return Err(error!(ErrorCode::MaxStakedReached));
+        }
+        if stake_pool.max_staked_per_wallet.is_some() && ctx.accounts.user_stake_registry.total_staked >= u64::from(stake_pool.max_staked_per_wallet.unwrap()) {
+            return Err(error!(ErrorCode::MaxStakedPerWalletReached));
+        }
+    }
 
     let cpi_accounts = Approve {
         to: ctx.accounts.user_stake_mint_token_account.to_account_info(),
@@ -98,19 +149,55 @@ pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts,
 
     // handle payment
     assert_payment_info(stake_pool.key(), Action::Stake, stake_pool.stake_payment_info)?;
-    handle_payment_info(stake_pool.stake_payment_info, remaining_accounts)?;
+    if !is_fee_exempt(stake_pool, Action::Stake, ctx.accounts.user.key(), remaining_accounts)? {
+        handle_payment_info(stake_pool.stake_payment_info, remaining_accounts)?;
+    }
+
+    //// FEATURE: Seasons
+    roll_stake_entry_season(stake_entry, stake_pool)?;
+
+    //// FEATURE: Stake seconds decay
+    if stake_entry.amount == 0 {
+        decay_stake_seconds(stake_entry, stake_pool)?;
+    }
 
     // update stake entry
     if stake_entry.amount != 0 {
-        increment_total_stake_seconds(stake_entry)?;
+        increment_total_stake_seconds(stake_entry, stake_pool)?;
         stake_entry.cooldown_start_seconds = None;
     }
     stake_entry.last_staker = ctx.accounts.user.key();
     stake_entry.last_staked_at = Clock::get().unwrap().unix_timestamp;
     stake_entry.last_updated_at = Clock::get().unwrap().unix_timestamp;
+    //// FEATURE: Warm-up
+    stake_entry.warmup_ends_at = stake_pool
+        .warmup_seconds
+        .map(|warmup_seconds| Clock::get().unwrap().unix_timestamp.checked_add(i64::from(warmup_seconds)).expect("Add error"));
+    //// FEATURE: Lock tiers
+    if let Some(lock_tier) = lock_tier {
+        let lock_tier = match stake_pool.lock_tiers.get(usize::from(lock_tier)) {
+            Some(lock_tier) => lock_tier,
+            None => return Err(error!(ErrorCode::InvalidLockTier)),
+        };
+        let unlock_at = Clock::get().unwrap().unix_timestamp.checked_add(i64::from(lock_tier.lock_seconds)).expect("Add error");
+        stake_entry.unlock_at = Some(max(stake_entry.unlock_at.unwrap_or(unlock_at), unlock_at));
+        stake_entry.lock_multiplier_basis_points = Some(lock_tier.multiplier_basis_points);
+    }
+    //// FEATURE: Loyalty ramp
+    stake_entry.loyalty_ramp = stake_pool.loyalty_ramp;
+    if stake_entry.amount == 0 {
+        stake_pool.total_staked = stake_pool.total_staked.checked_add(1).expect("Add error");
+    }
     stake_entry.amount = stake_entry.amount.checked_add(amount).unwrap();
-    stake_pool.total_staked = stake_pool.total_staked.checked_add(1).expect("Add error");
     stake_entry_fill_zeros(stake_entry)?;
 
+    //// FEATURE: User stake registry
+    let user_stake_registry = &mut ctx.accounts.user_stake_registry;
+    user_stake_registry.bump = *ctx.bumps.get("user_stake_registry").unwrap();
+    user_stake_registry.pool = stake_pool.key();
+    user_stake_registry.user = ctx.accounts.user.key();
+    user_stake_registry_stake(user_stake_registry, stake_entry);
+    resize_user_stake_registry(user_stake_registry, &ctx.accounts.user.to_account_info(), &ctx.accounts.system_program.to_account_info())?;
+
     Ok(())
 }
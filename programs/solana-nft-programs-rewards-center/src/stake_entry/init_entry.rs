@@ -2,6 +2,7 @@ use crate::authorization::mint_is_allowed;
 use crate::stake_seed;
 use crate::StakeEntry;
 use crate::StakePool;
+use crate::TraitProof;
 use crate::STAKE_ENTRY_PREFIX;
 use crate::STAKE_ENTRY_SIZE;
 use anchor_lang::prelude::*;
@@ -30,17 +31,19 @@ pub struct InitEntryCtx<'info> {
     system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<InitEntryCtx>, _user: Pubkey) -> Result<()> {
+pub fn handler(ctx: Context<InitEntryCtx>, user: Pubkey, mint_proof: Vec<[u8; 32]>, trait_proof: Option<TraitProof>) -> Result<()> {
     let stake_entry = &mut ctx.accounts.stake_entry;
     let stake_pool = &ctx.accounts.stake_pool;
     stake_entry.bump = *ctx.bumps.get("stake_entry").unwrap();
     stake_entry.pool = ctx.accounts.stake_pool.key();
     stake_entry.stake_mint = ctx.accounts.stake_mint.key();
     stake_entry.amount = 0;
+    stake_entry.rent_payer = ctx.accounts.payer.key();
+    stake_entry.season = ctx.accounts.stake_pool.season;
 
-    // check allowlist
+    // check allowlist, a permit's nonce is only consumed when staking
     let remaining_accounts = &mut ctx.remaining_accounts.iter();
-    mint_is_allowed(stake_pool, &ctx.accounts.stake_mint_metadata, ctx.accounts.stake_mint.key(), remaining_accounts)?;
+    mint_is_allowed(stake_pool, stake_entry, &ctx.accounts.stake_mint_metadata, user, &mint_proof, trait_proof.as_ref(), remaining_accounts)?;
 
     Ok(())
 }
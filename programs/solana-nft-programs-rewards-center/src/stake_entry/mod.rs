@@ -14,6 +14,7 @@ pub mod resize_stake_entry;
 pub use resize_stake_entry::*;
 
 pub mod multiplier_stake_seconds;
+pub use multiplier_stake_seconds::add_stake_entry_timed_multiplier::*;
 pub use multiplier_stake_seconds::decrement_stake_entry_multiplier_stake_seconds::*;
 pub use multiplier_stake_seconds::increment_stake_entry_multiplier_stake_seconds::*;
 pub use multiplier_stake_seconds::set_stake_entry_multiplier::*;
@@ -22,6 +23,18 @@ pub use multiplier_stake_seconds::set_stake_entry_multiplier_stake_seconds::*;
 pub mod close_stake_entry;
 pub use close_stake_entry::*;
 
+pub mod close_user_escrow;
+pub use close_user_escrow::*;
+
+pub mod cancel_cooldown;
+pub use cancel_cooldown::*;
+
+pub mod swap_stake;
+pub use swap_stake::*;
+
+pub mod backfill_user_stake_registry;
+pub use backfill_user_stake_registry::*;
+
 pub mod editions;
 pub use editions::stake_edition::*;
 pub use editions::unstake_edition::*;
@@ -0,0 +1,42 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::StakeEntry;
+use crate::StakePool;
+use crate::TimedMultiplier;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AddStakeEntryTimedMultiplierCtx<'info> {
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = stake_pool.key() == stake_entry.pool @ ErrorCode::InvalidStakePool)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+    #[account(mut, constraint = stake_pool.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AddStakeEntryTimedMultiplierCtx>, multiplier_basis_points: u64, expires_at: i64) -> Result<()> {
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    let now = Clock::get().unwrap().unix_timestamp;
+    if expires_at <= now {
+        return Err(error!(ErrorCode::InvalidTimedMultiplierExpiry));
+    }
+
+    // accrue at the current rate so the new multiplier only applies from now
+    increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+
+    // expired multipliers have been fully accounted for and can be replaced
+    for timed_multiplier in stake_entry.timed_multipliers.iter_mut() {
+        if timed_multiplier.is_some() && timed_multiplier.unwrap().expires_at <= now {
+            *timed_multiplier = None;
+        }
+    }
+    let slot = match stake_entry.timed_multipliers.iter_mut().find(|t| t.is_none()) {
+        Some(slot) => slot,
+        None => return Err(error!(ErrorCode::TimedMultipliersFull)),
+    };
+    *slot = Some(TimedMultiplier { multiplier_basis_points, expires_at });
+    stake_entry_fill_zeros(stake_entry)?;
+
+    Ok(())
+}
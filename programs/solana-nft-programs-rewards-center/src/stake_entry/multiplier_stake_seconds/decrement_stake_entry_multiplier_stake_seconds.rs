@@ -19,7 +19,7 @@ pub fn handler(ctx: Context<DecrementStakeEntryMultiplierStakeSecondsCtx>, multi
     if stake_entry.multiplier_basis_points.is_none() {
         stake_entry.multiplier_basis_points = Some(BASIS_POINTS_DIVISOR);
     }
-    increment_total_stake_seconds(stake_entry)?;
+    increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
     stake_entry.multiplier_stake_seconds = Some(
         stake_entry
             .multiplier_stake_seconds
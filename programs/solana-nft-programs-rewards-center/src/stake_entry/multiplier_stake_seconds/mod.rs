@@ -9,3 +9,6 @@ pub use decrement_stake_entry_multiplier_stake_seconds::*;
 
 pub mod increment_stake_entry_multiplier_stake_seconds;
 pub use increment_stake_entry_multiplier_stake_seconds::*;
+
+pub mod add_stake_entry_timed_multiplier;
+pub use add_stake_entry_timed_multiplier::*;
@@ -1,24 +1,35 @@
+use crate::errors::ErrorCode;
 use crate::utils::resize_account;
 use crate::{stake_entry_fill_zeros, StakeEntry, STAKE_ENTRY_SIZE};
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
 
 #[derive(Accounts)]
 pub struct ResizeStakeEntryCtx<'info> {
-    #[account(mut)]
-    stake_entry: Account<'info, StakeEntry>,
+    /// CHECK: Checked in handler, entries created with an older layout cannot be deserialized until resized
+    #[account(mut, owner = crate::id() @ ErrorCode::InvalidStakeEntry)]
+    stake_entry: UncheckedAccount<'info>,
     #[account(mut)]
     payer: Signer<'info>,
     system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<ResizeStakeEntryCtx>) -> Result<()> {
-    let stake_entry = &mut ctx.accounts.stake_entry;
-    stake_entry_fill_zeros(stake_entry)?;
+    let stake_entry_info = ctx.accounts.stake_entry.to_account_info();
+    if stake_entry_info.try_borrow_data()?[..8] != StakeEntry::DISCRIMINATOR {
+        return Err(error!(ErrorCode::InvalidStakeEntry));
+    }
     resize_account(
-        &ctx.accounts.stake_entry.to_account_info(),
+        &stake_entry_info,
         STAKE_ENTRY_SIZE,
         &ctx.accounts.payer.to_account_info(),
         &ctx.accounts.system_program.to_account_info(),
     )?;
+
+    let mut stake_entry = match Account::<StakeEntry>::try_from(&stake_entry_info) {
+        Ok(stake_entry) => stake_entry,
+        Err(_) => return Err(error!(ErrorCode::InvalidStakeEntry)),
+    };
+    stake_entry_fill_zeros(&mut stake_entry)?;
     Ok(())
 }
@@ -1,12 +1,17 @@
 use crate::errors::ErrorCode;
+use crate::utils::resize_account;
+use crate::LoyaltyRamp;
 use anchor_lang::prelude::*;
 
 pub const USER_ESCROW_PREFIX: &str = "escrow";
-pub const USER_ESCROW_SIZE: usize = 8 + std::mem::size_of::<UserEscrow>() + 8;
+// delegations is stored in the 8 bytes of padding escrows were always allocated with, so
+// existing escrows keep their size and read it as zero
+pub const USER_ESCROW_SIZE: usize = 8 + 1 + 32 + 8;
 #[account]
 pub struct UserEscrow {
     pub bump: u8,
     pub user: Pubkey,
+    pub delegations: u64,
 }
 
 #[inline]
@@ -20,6 +25,82 @@ pub fn escrow_seeds(user: &Pubkey, expected_key: &Pubkey) -> Result<Vec<Vec<u8>>
     Ok(seeds)
 }
 
+pub const USER_STAKE_REGISTRY_PREFIX: &str = "user-stake-registry";
+pub const USER_STAKE_REGISTRY_DEFAULT_SIZE: usize = 8 + 1 + 32 + 32 + 4 + 8 + 16 + 8;
+#[account]
+pub struct UserStakeRegistry {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub stake_entries: Vec<UserStakeRegistryEntry>,
+    // staked entries, in the same unit as StakePool total_staked
+    pub total_staked: u64,
+    pub total_stake_seconds: u128,
+    // highest stake permit nonce used in the pool, kept here so closing a stake entry cannot reset it
+    pub permit_nonce: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct UserStakeRegistryEntry {
+    pub stake_entry: Pubkey,
+    // stake entry total_stake_seconds already credited to the registry
+    pub synced_stake_seconds: u128,
+}
+
+pub fn user_stake_registry_sync(user_stake_registry: &mut UserStakeRegistry, stake_entry: &Account<StakeEntry>) {
+    if let Some(registry_entry) = user_stake_registry.stake_entries.iter_mut().find(|e| e.stake_entry == stake_entry.key()) {
+        let seconds_increased = stake_entry.total_stake_seconds.saturating_sub(registry_entry.synced_stake_seconds);
+        registry_entry.synced_stake_seconds = stake_entry.total_stake_seconds;
+        user_stake_registry.total_stake_seconds = user_stake_registry.total_stake_seconds.saturating_add(seconds_increased);
+    }
+}
+
+pub fn user_stake_registry_stake(user_stake_registry: &mut UserStakeRegistry, stake_entry: &Account<StakeEntry>) {
+    if user_stake_registry.stake_entries.iter().any(|e| e.stake_entry == stake_entry.key()) {
+        user_stake_registry_sync(user_stake_registry, stake_entry);
+    } else {
+        user_stake_registry.stake_entries.push(UserStakeRegistryEntry {
+            stake_entry: stake_entry.key(),
+            synced_stake_seconds: stake_entry.total_stake_seconds,
+        });
+        user_stake_registry.total_staked = user_stake_registry.total_staked.checked_add(1).expect("Add error");
+    }
+}
+
+pub fn user_stake_registry_unstake(user_stake_registry: &mut UserStakeRegistry, stake_entry: &Account<StakeEntry>) {
+    // entries staked before the registry existed are not tracked
+    if user_stake_registry.stake_entries.iter().any(|e| e.stake_entry == stake_entry.key()) {
+        user_stake_registry_sync(user_stake_registry, stake_entry);
+        user_stake_registry.stake_entries.retain(|e| e.stake_entry != stake_entry.key());
+        user_stake_registry.total_staked = user_stake_registry.total_staked.saturating_sub(1);
+    }
+}
+
+pub fn user_stake_registry_use_permit(user_stake_registry: &mut UserStakeRegistry, permit_nonce: Option<u64>) -> Result<()> {
+    if let Some(permit_nonce) = permit_nonce {
+        if permit_nonce <= user_stake_registry.permit_nonce {
+            return Err(error!(ErrorCode::StakePermitNonceUsed));
+        }
+        user_stake_registry.permit_nonce = permit_nonce;
+    }
+    Ok(())
+}
+
+pub fn user_stake_registry_swap(user_stake_registry: &mut UserStakeRegistry, from_stake_entry: &Account<StakeEntry>, to_stake_entry: &Account<StakeEntry>) {
+    // seconds swapped out of a tracked entry are only credited again through the entry they moved to
+    if let Some(registry_entry) = user_stake_registry.stake_entries.iter_mut().find(|e| e.stake_entry == from_stake_entry.key()) {
+        let seconds_decreased = registry_entry.synced_stake_seconds.saturating_sub(from_stake_entry.total_stake_seconds);
+        registry_entry.synced_stake_seconds = from_stake_entry.total_stake_seconds;
+        user_stake_registry.total_stake_seconds = user_stake_registry.total_stake_seconds.saturating_sub(seconds_decreased);
+    }
+    user_stake_registry_sync(user_stake_registry, to_stake_entry);
+}
+
+pub fn resize_user_stake_registry<'info>(user_stake_registry: &Account<'info, UserStakeRegistry>, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
+    let new_space = std::cmp::max(user_stake_registry.try_to_vec()?.len() + 8, USER_STAKE_REGISTRY_DEFAULT_SIZE);
+    resize_account(&user_stake_registry.to_account_info(), new_space, payer, system_program)
+}
+
 pub fn stake_seed(supply: u64, user: Pubkey) -> Pubkey {
     if supply > 1 {
         user
@@ -45,6 +126,35 @@ pub struct StakeEntry {
     pub cooldown_start_seconds: Option<i64>,
     pub multiplier_stake_seconds: Option<u128>,
     pub multiplier_basis_points: Option<u64>,
+    pub denied: bool,
+    pub stake_authorization_record: Option<Pubkey>,
+    pub authorization_expires_at: Option<i64>,
+    pub authorization_max_stake_seconds: Option<u128>,
+    // seconds accrued under the current record, measured against its budget
+    pub authorization_stake_seconds: u128,
+    pub rent_payer: Pubkey,
+    pub unlock_at: Option<i64>,
+    pub lock_multiplier_basis_points: Option<u64>,
+    pub loyalty_ramp: Option<LoyaltyRamp>,
+    pub timed_multipliers: [Option<TimedMultiplier>; MAX_TIMED_MULTIPLIERS],
+    pub stake_set: Option<Pubkey>,
+    pub stake_set_multiplier_basis_points: Option<u64>,
+    pub team: Option<Pubkey>,
+    pub team_stake_seconds_checkpoint: u128,
+    // seconds accrued in the team before a reset, credited at the next sync
+    pub team_pending_stake_seconds: u128,
+    pub season: u32,
+    pub previous_season_stake_seconds: u128,
+    pub unstaked_at: Option<i64>,
+    pub warmup_ends_at: Option<i64>,
+    pub swapped_in_stake_seconds: u128,
+}
+
+pub const MAX_TIMED_MULTIPLIERS: usize = 4;
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct TimedMultiplier {
+    pub multiplier_basis_points: u64,
+    pub expires_at: i64,
 }
 
 pub fn stake_entry_fill_zeros(stake_entry: &mut Account<StakeEntry>) -> Result<()> {
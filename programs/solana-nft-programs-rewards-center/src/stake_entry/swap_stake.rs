@@ -0,0 +1,166 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::reward_distribution::sync_reward_entry_checkpoints;
+use crate::reward_distribution::RewardEntry;
+use crate::stake_entry_fill_zeros;
+use crate::user_stake_registry_swap;
+use crate::user_stake_registry_sync;
+use crate::StakeEntry;
+use crate::StakePool;
+use crate::UserStakeRegistry;
+use crate::BASIS_POINTS_DIVISOR;
+use crate::USER_STAKE_REGISTRY_DEFAULT_SIZE;
+use crate::USER_STAKE_REGISTRY_PREFIX;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use solana_program::sysvar;
+use solana_program::sysvar::instructions::load_current_index_checked;
+use solana_program::sysvar::instructions::load_instruction_at_checked;
+
+#[derive(Accounts)]
+pub struct SwapStakeCtx<'info> {
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = from_stake_entry.pool == stake_pool.key() && from_stake_entry.last_staker == user.key() && !from_stake_entry.denied @ ErrorCode::InvalidStakeSwap)]
+    from_stake_entry: Box<Account<'info, StakeEntry>>,
+    #[account(mut, constraint = to_stake_entry.pool == stake_pool.key() && to_stake_entry.last_staker == user.key() && !to_stake_entry.denied && to_stake_entry.amount > 0 && to_stake_entry.key() != from_stake_entry.key() @ ErrorCode::InvalidStakeSwap)]
+    to_stake_entry: Box<Account<'info, StakeEntry>>,
+    #[account(mut)]
+    user: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = USER_STAKE_REGISTRY_DEFAULT_SIZE,
+        seeds = [USER_STAKE_REGISTRY_PREFIX.as_bytes(), stake_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    user_stake_registry: Box<Account<'info, UserStakeRegistry>>,
+    /// CHECK: Address checked
+    #[account(address = sysvar::instructions::id())]
+    sysvar_instructions: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+fn swap_portion(seconds: u128, swap_stake_basis_points: u64) -> u128 {
+    seconds
+        .checked_mul(u128::from(swap_stake_basis_points))
+        .expect("Mul error")
+        .checked_div(u128::from(BASIS_POINTS_DIVISOR))
+        .expect("Div error")
+}
+
+fn unstaked_later_in_transaction(sysvar_instructions: &AccountInfo, stake_entry: Pubkey) -> Result<bool> {
+    let unstake_discriminators = [
+        crate::instruction::UnstakeEdition::DISCRIMINATOR,
+        crate::instruction::UnstakePnft::DISCRIMINATOR,
+        crate::instruction::UnstakeCcs::DISCRIMINATOR,
+    ];
+    let mut index = load_current_index_checked(sysvar_instructions)?;
+    loop {
+        index = index.checked_add(1).expect("Add error");
+        let instruction = match load_instruction_at_checked(usize::from(index), sysvar_instructions) {
+            Ok(instruction) => instruction,
+            Err(_) => return Ok(false),
+        };
+        // every unstake instruction takes the stake entry right after the stake pool
+        if instruction.program_id == crate::id()
+            && instruction.data.len() >= 8
+            && unstake_discriminators.iter().any(|discriminator| instruction.data[..8] == discriminator[..])
+            && instruction.accounts.get(1).map(|account| account.pubkey) == Some(stake_entry)
+        {
+            return Ok(true);
+        }
+    }
+}
+
+// composed with stake_*(to) before and unstake_*(from) after in one transaction
+pub fn handler(ctx: Context<SwapStakeCtx>) -> Result<()> {
+    let stake_pool = &ctx.accounts.stake_pool;
+    let swap_stake_basis_points = match stake_pool.swap_stake_basis_points {
+        Some(swap_stake_basis_points) if swap_stake_basis_points <= BASIS_POINTS_DIVISOR => swap_stake_basis_points,
+        Some(_) => return Err(error!(ErrorCode::InvalidStakeSwap)),
+        None => return Err(error!(ErrorCode::StakeSwapDisabled)),
+    };
+    // the unstake failing reverts the swap, so seconds cannot be moved without giving up the source
+    if !unstaked_later_in_transaction(&ctx.accounts.sysvar_instructions, ctx.accounts.from_stake_entry.key())? {
+        return Err(error!(ErrorCode::StakeSwapSourceNotUnstaked));
+    }
+    // an unstake that only starts the cooldown leaves the source staked
+    if let Some(cooldown_seconds) = stake_pool.cooldown_seconds.filter(|cooldown_seconds| *cooldown_seconds > 0) {
+        match ctx.accounts.from_stake_entry.cooldown_start_seconds {
+            Some(cooldown_start_seconds) if ((Clock::get().unwrap().unix_timestamp - cooldown_start_seconds) as u32) >= cooldown_seconds => {}
+            _ => return Err(error!(ErrorCode::CooldownSecondRemaining)),
+        }
+    }
+    let from_stake_entry = &mut ctx.accounts.from_stake_entry;
+    let to_stake_entry = &mut ctx.accounts.to_stake_entry;
+    increment_total_stake_seconds(from_stake_entry, stake_pool)?;
+    increment_total_stake_seconds(to_stake_entry, stake_pool)?;
+
+    //// FEATURE: User stake registry
+    let user_stake_registry = &mut ctx.accounts.user_stake_registry;
+    user_stake_registry.bump = *ctx.bumps.get("user_stake_registry").unwrap();
+    user_stake_registry.pool = stake_pool.key();
+    user_stake_registry.user = ctx.accounts.user.key();
+    user_stake_registry_sync(user_stake_registry, from_stake_entry);
+    user_stake_registry_sync(user_stake_registry, to_stake_entry);
+
+    // move reward checkpoints first so the swapped in seconds are not treated as unmigrated
+    let swapped_stake_seconds = swap_portion(from_stake_entry.total_stake_seconds, swap_stake_basis_points);
+    let swapped_in_stake_seconds = to_stake_entry.swapped_in_stake_seconds.checked_add(swapped_stake_seconds).expect("Add error");
+    let mut reward_distributors: Vec<Pubkey> = Vec::new();
+    for reward_entry_infos in ctx.remaining_accounts.chunks(2) {
+        let (mut from_reward_entry, mut to_reward_entry) = match reward_entry_infos {
+            [from_reward_entry_info, to_reward_entry_info] => match (Account::<RewardEntry>::try_from(from_reward_entry_info), Account::<RewardEntry>::try_from(to_reward_entry_info)) {
+                (Ok(from_reward_entry), Ok(to_reward_entry)) => (from_reward_entry, to_reward_entry),
+                _ => return Err(error!(ErrorCode::InvalidStakeSwapRewardEntry)),
+            },
+            _ => return Err(error!(ErrorCode::InvalidStakeSwapRewardEntry)),
+        };
+        if from_reward_entry.stake_entry != from_stake_entry.key()
+            || to_reward_entry.stake_entry != to_stake_entry.key()
+            || from_reward_entry.reward_distributor != to_reward_entry.reward_distributor
+            || reward_distributors.contains(&from_reward_entry.reward_distributor)
+        {
+            return Err(error!(ErrorCode::InvalidStakeSwapRewardEntry));
+        }
+        reward_distributors.push(from_reward_entry.reward_distributor);
+
+        sync_reward_entry_checkpoints(&mut from_reward_entry, from_stake_entry);
+        sync_reward_entry_checkpoints(&mut to_reward_entry, to_stake_entry);
+        let swapped_reward_seconds = swap_portion(from_reward_entry.reward_seconds_received, swap_stake_basis_points);
+        from_reward_entry.reward_seconds_received = from_reward_entry.reward_seconds_received.checked_sub(swapped_reward_seconds).expect("Sub error");
+        to_reward_entry.reward_seconds_received = to_reward_entry.reward_seconds_received.checked_add(swapped_reward_seconds).expect("Add error");
+        to_reward_entry.swapped_in_stake_seconds = swapped_in_stake_seconds;
+        from_reward_entry.exit(&crate::id())?;
+        to_reward_entry.exit(&crate::id())?;
+    }
+
+    if let Some(multiplier_stake_seconds) = from_stake_entry.multiplier_stake_seconds {
+        let swapped_multiplier_stake_seconds = swap_portion(multiplier_stake_seconds, swap_stake_basis_points);
+        from_stake_entry.multiplier_stake_seconds = Some(multiplier_stake_seconds.checked_sub(swapped_multiplier_stake_seconds).expect("Sub error"));
+        to_stake_entry.multiplier_stake_seconds = Some(
+            to_stake_entry
+                .multiplier_stake_seconds
+                .unwrap_or(to_stake_entry.total_stake_seconds)
+                .checked_add(swapped_multiplier_stake_seconds)
+                .expect("Add error"),
+        );
+    } else if let Some(multiplier_stake_seconds) = to_stake_entry.multiplier_stake_seconds {
+        to_stake_entry.multiplier_stake_seconds = Some(multiplier_stake_seconds.checked_add(swapped_stake_seconds).expect("Add error"));
+    }
+    // spent seconds move too so receipts cannot be claimed twice with them
+    let swapped_used_stake_seconds = swap_portion(from_stake_entry.used_stake_seconds, swap_stake_basis_points);
+    from_stake_entry.used_stake_seconds = from_stake_entry.used_stake_seconds.checked_sub(swapped_used_stake_seconds).expect("Sub error");
+    to_stake_entry.used_stake_seconds = to_stake_entry.used_stake_seconds.checked_add(swapped_used_stake_seconds).expect("Add error");
+    // keep team checkpoints aligned so the seconds are not credited to a team again
+    from_stake_entry.team_stake_seconds_checkpoint = from_stake_entry.team_stake_seconds_checkpoint.saturating_sub(swapped_stake_seconds);
+    to_stake_entry.team_stake_seconds_checkpoint = to_stake_entry.team_stake_seconds_checkpoint.checked_add(swapped_stake_seconds).expect("Add error");
+
+    from_stake_entry.total_stake_seconds = from_stake_entry.total_stake_seconds.checked_sub(swapped_stake_seconds).expect("Sub error");
+    to_stake_entry.total_stake_seconds = to_stake_entry.total_stake_seconds.checked_add(swapped_stake_seconds).expect("Add error");
+    to_stake_entry.swapped_in_stake_seconds = swapped_in_stake_seconds;
+    user_stake_registry_swap(user_stake_registry, from_stake_entry, to_stake_entry);
+    stake_entry_fill_zeros(from_stake_entry)?;
+    stake_entry_fill_zeros(to_stake_entry)?;
+    Ok(())
+}
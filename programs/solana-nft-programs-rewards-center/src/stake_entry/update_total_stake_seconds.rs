@@ -1,47 +1,270 @@
 use crate::errors::ErrorCode;
-use crate::{StakeEntry, BASIS_POINTS_DIVISOR};
+use crate::leaderboard_update;
+use crate::team::defer_team_stake_seconds;
+use crate::Leaderboard;
+use crate::MAX_LOYALTY_RAMP_STEPS;
+use crate::{StakeEntry, StakePool, StakeSecondsDecayKind, BASIS_POINTS_DIVISOR};
 use anchor_lang::prelude::*;
+use std::cmp::max;
+use std::cmp::min;
 
 #[derive(Accounts)]
 pub struct UpdateTotalStakeSecondsCtx<'info> {
+    #[account(constraint = stake_pool.key() == stake_entry.pool @ ErrorCode::InvalidStakePool)]
+    stake_pool: Box<Account<'info, StakePool>>,
     #[account(mut, constraint = stake_entry.last_staker != Pubkey::default() @ ErrorCode::CannotUpdateUnstakedEntry)]
     stake_entry: Account<'info, StakeEntry>,
 
     #[account(mut)]
     updater: Signer<'info>,
+    #[account(mut, constraint = leaderboard.pool == stake_pool.key() @ ErrorCode::InvalidLeaderboard)]
+    leaderboard: Option<Box<Account<'info, Leaderboard>>>,
 }
 
 pub fn handler(ctx: Context<UpdateTotalStakeSecondsCtx>) -> Result<()> {
     let stake_entry = &mut ctx.accounts.stake_entry;
 
     //// FEATURE: Cooldown
-    if stake_entry.cooldown_start_seconds.is_some() {
+    if stake_entry.cooldown_start_seconds.is_some() && !ctx.accounts.stake_pool.accrue_during_cooldown {
         return Err(error!(ErrorCode::CooldownSecondRemaining));
     }
 
-    increment_total_stake_seconds(stake_entry)?;
+    increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+
+    //// FEATURE: Leaderboard
+    if let Some(leaderboard) = &mut ctx.accounts.leaderboard {
+        leaderboard_update(leaderboard, &ctx.accounts.stake_pool, stake_entry, None)?;
+    }
     Ok(())
 }
 
-pub fn increment_total_stake_seconds(stake_entry: &mut Account<StakeEntry>) -> Result<()> {
-    let seconds_increased = (u128::try_from(stake_entry.cooldown_start_seconds.unwrap_or(Clock::get().unwrap().unix_timestamp))
-        .unwrap()
-        .saturating_sub(u128::try_from(stake_entry.last_updated_at).unwrap()))
-    .checked_mul(u128::try_from(stake_entry.amount).unwrap())
-    .expect("Mul error");
-    if let Some(multiplier_basis_points) = stake_entry.multiplier_basis_points {
-        let base_seconds = stake_entry.multiplier_stake_seconds.unwrap_or(stake_entry.total_stake_seconds);
-        stake_entry.multiplier_stake_seconds = Some(
-            base_seconds.saturating_add(
-                seconds_increased
-                    .checked_mul(u128::from(multiplier_basis_points))
+pub fn increment_total_stake_seconds(stake_entry: &mut Account<StakeEntry>, stake_pool: &StakePool) -> Result<()> {
+    //// FEATURE: Seasons
+    roll_stake_entry_season(stake_entry, stake_pool)?;
+    accrue_stake_seconds(stake_entry, stake_pool, Clock::get().unwrap().unix_timestamp)
+}
+
+pub fn roll_stake_entry_season(stake_entry: &mut Account<StakeEntry>, stake_pool: &StakePool) -> Result<()> {
+    if stake_entry.season == stake_pool.season {
+        return Ok(());
+    }
+    // credit the previous season up to when the current one started
+    if stake_entry.last_updated_at < stake_pool.season_started_at {
+        accrue_stake_seconds(stake_entry, stake_pool, stake_pool.season_started_at)?;
+    }
+    stake_entry.last_updated_at = max(stake_entry.last_updated_at, stake_pool.season_started_at);
+    stake_entry.previous_season_stake_seconds = stake_entry.total_stake_seconds;
+    defer_team_stake_seconds(stake_entry);
+    stake_entry.total_stake_seconds = 0;
+    stake_entry.used_stake_seconds = 0;
+    stake_entry.multiplier_stake_seconds = None;
+    stake_entry.swapped_in_stake_seconds = 0;
+    stake_entry.season = stake_pool.season;
+    Ok(())
+}
+
+pub fn decay_stake_seconds(stake_entry: &mut Account<StakeEntry>, stake_pool: &StakePool) -> Result<()> {
+    let (stake_seconds_decay, unstaked_at) = match (stake_pool.stake_seconds_decay, stake_entry.unstaked_at) {
+        (Some(stake_seconds_decay), Some(unstaked_at)) => (stake_seconds_decay, unstaked_at),
+        _ => return Ok(()),
+    };
+    if stake_seconds_decay.period_seconds == 0 {
+        return Err(error!(ErrorCode::InvalidStakeSecondsDecay));
+    }
+    let period_seconds = u128::from(stake_seconds_decay.period_seconds);
+    let unstaked_seconds = u128::try_from(Clock::get().unwrap().unix_timestamp.saturating_sub(unstaked_at).max(0)).unwrap();
+    let decay = |seconds: u128| -> Result<u128> {
+        match stake_seconds_decay.kind {
+            k if k == StakeSecondsDecayKind::HalfLife as u8 => {
+                let halvings = unstaked_seconds / period_seconds;
+                if halvings >= 128 {
+                    return Ok(0);
+                }
+                // linear between whole halvings
+                let remaining_seconds = unstaked_seconds % period_seconds;
+                Ok((seconds >> halvings)
+                    .checked_mul(period_seconds.checked_mul(2).expect("Mul error").checked_sub(remaining_seconds).expect("Sub error"))
                     .expect("Mul error")
-                    .checked_div(u128::from(BASIS_POINTS_DIVISOR))
-                    .expect("Div error"),
-            ),
-        );
+                    .checked_div(period_seconds.checked_mul(2).expect("Mul error"))
+                    .expect("Div error"))
+            }
+            k if k == StakeSecondsDecayKind::Linear as u8 => Ok(seconds
+                .checked_mul(period_seconds.saturating_sub(unstaked_seconds))
+                .expect("Mul error")
+                .checked_div(period_seconds)
+                .expect("Div error")),
+            _ => Err(error!(ErrorCode::InvalidStakeSecondsDecay)),
+        }
+    };
+
+    // seconds not yet synced to a team were earned before the decay, so bank them as pending
+    if stake_entry.team.is_some() {
+        let seconds_increased = stake_entry.total_stake_seconds.saturating_sub(stake_entry.team_stake_seconds_checkpoint);
+        stake_entry.team_pending_stake_seconds = stake_entry.team_pending_stake_seconds.saturating_add(seconds_increased);
+    }
+    let total_stake_seconds = decay(stake_entry.total_stake_seconds)?;
+    stake_entry.total_stake_seconds = total_stake_seconds;
+    stake_entry.multiplier_stake_seconds = match stake_entry.multiplier_stake_seconds {
+        Some(multiplier_stake_seconds) => Some(decay(multiplier_stake_seconds)?),
+        None => None,
+    };
+    // spent seconds are not decayed twice
+    stake_entry.used_stake_seconds = min(stake_entry.used_stake_seconds, stake_entry.total_stake_seconds);
+    if stake_entry.team.is_some() {
+        stake_entry.team_stake_seconds_checkpoint = stake_entry.total_stake_seconds;
+    }
+    stake_entry.unstaked_at = None;
+    Ok(())
+}
+
+fn accrue_stake_seconds(stake_entry: &mut Account<StakeEntry>, stake_pool: &StakePool, now: i64) -> Result<()> {
+    //// FEATURE: Denied mints do not accrue
+    if stake_entry.denied {
+        stake_entry.last_updated_at = now;
+        return Ok(());
+    }
+
+    let mut accrue_until = match stake_entry.cooldown_start_seconds {
+        Some(cooldown_start_seconds) if !stake_pool.accrue_during_cooldown => min(cooldown_start_seconds, now),
+        _ => now,
+    };
+    //// FEATURE: Authorization expiry
+    if let Some(authorization_expires_at) = stake_entry.authorization_expires_at {
+        accrue_until = min(accrue_until, authorization_expires_at);
+    }
+    //// FEATURE: Warm-up
+    if stake_entry.warmup_ends_at.is_some() && accrue_until < stake_entry.warmup_ends_at.unwrap() {
+        // held back and credited in full once the warm-up completes
+        return Ok(());
+    }
+    let mut seconds_increased = (u128::try_from(accrue_until).unwrap().saturating_sub(u128::try_from(stake_entry.last_updated_at).unwrap()))
+        .checked_mul(u128::try_from(stake_entry.amount).unwrap())
+        .expect("Mul error");
+    //// FEATURE: Authorization stake seconds budget
+    if let Some(authorization_max_stake_seconds) = stake_entry.authorization_max_stake_seconds {
+        seconds_increased = min(seconds_increased, authorization_max_stake_seconds.saturating_sub(stake_entry.authorization_stake_seconds));
+        stake_entry.authorization_stake_seconds = stake_entry.authorization_stake_seconds.saturating_add(seconds_increased);
+    }
+    if stake_entry.multiplier_basis_points.is_some()
+        || stake_entry.lock_multiplier_basis_points.is_some()
+        || stake_entry.loyalty_ramp.is_some()
+        || stake_entry.timed_multipliers.iter().any(|t| t.is_some())
+        || !stake_pool.boost_windows.is_empty()
+        || stake_entry.stake_set_multiplier_basis_points.is_some()
+    {
+        let base_seconds = stake_entry.multiplier_stake_seconds.unwrap_or(stake_entry.total_stake_seconds);
+        let elapsed_seconds = u128::try_from(accrue_until.saturating_sub(stake_entry.last_updated_at).max(0)).unwrap();
+        let multiplier_seconds_increased = if elapsed_seconds == 0 {
+            0
+        } else {
+            // spread the (possibly capped) seconds across the multiplier in effect over each part of the period
+            multiplier_basis_points_seconds(stake_entry, stake_pool, stake_entry.last_updated_at, accrue_until)
+                .checked_mul(seconds_increased)
+                .expect("Mul error")
+                .checked_div(elapsed_seconds.checked_mul(u128::from(BASIS_POINTS_DIVISOR)).expect("Mul error"))
+                .expect("Div error")
+        };
+        stake_entry.multiplier_stake_seconds = Some(base_seconds.saturating_add(multiplier_seconds_increased));
     }
     stake_entry.total_stake_seconds = stake_entry.total_stake_seconds.saturating_add(seconds_increased);
-    stake_entry.last_updated_at = Clock::get().unwrap().unix_timestamp;
+    stake_entry.last_updated_at = now;
     Ok(())
 }
+
+// sum of seconds times the multiplier basis points in effect, split wherever the multiplier changes
+fn multiplier_basis_points_seconds(stake_entry: &StakeEntry, stake_pool: &StakePool, start: i64, end: i64) -> u128 {
+    let mut breakpoints = vec![start, end];
+    if let Some(unlock_at) = stake_entry.unlock_at {
+        breakpoints.push(unlock_at);
+    }
+    for timed_multiplier in stake_entry.timed_multipliers.iter().flatten() {
+        breakpoints.push(timed_multiplier.expires_at);
+    }
+    for boost_window in stake_pool.boost_windows.iter() {
+        breakpoints.push(boost_window.start);
+        breakpoints.push(boost_window.end);
+    }
+    if let Some(loyalty_ramp) = stake_entry.loyalty_ramp {
+        if loyalty_ramp.interval_seconds > 0 && loyalty_ramp.increment_basis_points > 0 {
+            // only steps up to the cap change the multiplier
+            let max_steps = min(
+                loyalty_ramp.max_bonus_basis_points.saturating_add(loyalty_ramp.increment_basis_points - 1) / loyalty_ramp.increment_basis_points,
+                MAX_LOYALTY_RAMP_STEPS,
+            );
+            let interval_seconds = i64::from(loyalty_ramp.interval_seconds);
+            let first_step = max((start - stake_entry.last_staked_at) / interval_seconds, 1);
+            let last_step = min((end - stake_entry.last_staked_at) / interval_seconds, i64::try_from(max_steps).unwrap());
+            for step in first_step..=last_step {
+                breakpoints.push(stake_entry.last_staked_at + step * interval_seconds);
+            }
+        }
+    }
+    breakpoints.retain(|t| *t >= start && *t <= end);
+    breakpoints.sort_unstable();
+    breakpoints.dedup();
+
+    breakpoints
+        .windows(2)
+        .map(|period| {
+            u128::try_from(period[1] - period[0])
+                .unwrap()
+                .checked_mul(multiplier_basis_points_at(stake_entry, stake_pool, period[0]))
+                .expect("Mul error")
+        })
+        .fold(0, |total, seconds| total.checked_add(seconds).expect("Add error"))
+}
+
+fn multiplier_basis_points_at(stake_entry: &StakeEntry, stake_pool: &StakePool, timestamp: i64) -> u128 {
+    let mut multiplier_basis_points = u128::from(stake_entry.multiplier_basis_points.unwrap_or(BASIS_POINTS_DIVISOR));
+    //// FEATURE: Lock tiers
+    if let (Some(unlock_at), Some(lock_multiplier_basis_points)) = (stake_entry.unlock_at, stake_entry.lock_multiplier_basis_points) {
+        if timestamp < unlock_at {
+            multiplier_basis_points = multiplier_basis_points
+                .checked_mul(u128::from(lock_multiplier_basis_points))
+                .expect("Mul error")
+                .checked_div(u128::from(BASIS_POINTS_DIVISOR))
+                .expect("Div error");
+        }
+    }
+    //// FEATURE: Timed multipliers
+    for timed_multiplier in stake_entry.timed_multipliers.iter().flatten() {
+        if timestamp < timed_multiplier.expires_at {
+            multiplier_basis_points = multiplier_basis_points
+                .checked_mul(u128::from(timed_multiplier.multiplier_basis_points))
+                .expect("Mul error")
+                .checked_div(u128::from(BASIS_POINTS_DIVISOR))
+                .expect("Div error");
+        }
+    }
+    //// FEATURE: Stake sets
+    if let Some(stake_set_multiplier_basis_points) = stake_entry.stake_set_multiplier_basis_points {
+        multiplier_basis_points = multiplier_basis_points
+            .checked_mul(u128::from(stake_set_multiplier_basis_points))
+            .expect("Mul error")
+            .checked_div(u128::from(BASIS_POINTS_DIVISOR))
+            .expect("Div error");
+    }
+    //// FEATURE: Pool boost windows
+    for boost_window in stake_pool.boost_windows.iter() {
+        if boost_window.start <= timestamp && timestamp < boost_window.end {
+            multiplier_basis_points = multiplier_basis_points
+                .checked_mul(u128::from(boost_window.multiplier_basis_points))
+                .expect("Mul error")
+                .checked_div(u128::from(BASIS_POINTS_DIVISOR))
+                .expect("Div error");
+        }
+    }
+    //// FEATURE: Loyalty ramp
+    if let Some(loyalty_ramp) = stake_entry.loyalty_ramp {
+        if loyalty_ramp.interval_seconds > 0 && timestamp > stake_entry.last_staked_at {
+            let steps = u64::try_from((timestamp - stake_entry.last_staked_at) / i64::from(loyalty_ramp.interval_seconds)).unwrap();
+            let bonus_basis_points = min(steps.saturating_mul(loyalty_ramp.increment_basis_points), loyalty_ramp.max_bonus_basis_points);
+            multiplier_basis_points = multiplier_basis_points
+                .checked_mul(u128::from(BASIS_POINTS_DIVISOR.checked_add(bonus_basis_points).expect("Add error")))
+                .expect("Mul error")
+                .checked_div(u128::from(BASIS_POINTS_DIVISOR))
+                .expect("Div error");
+        }
+    }
+    multiplier_basis_points
+}
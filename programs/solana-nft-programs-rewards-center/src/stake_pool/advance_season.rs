@@ -0,0 +1,19 @@
+use crate::errors::ErrorCode;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AdvanceSeasonCtx<'info> {
+    #[account(mut)]
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(constraint = stake_pool.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AdvanceSeasonCtx>) -> Result<()> {
+    // entries roll over lazily the next time they are touched
+    let stake_pool = &mut ctx.accounts.stake_pool;
+    stake_pool.season = stake_pool.season.checked_add(1).expect("Add error");
+    stake_pool.season_started_at = Clock::get().unwrap().unix_timestamp;
+    Ok(())
+}
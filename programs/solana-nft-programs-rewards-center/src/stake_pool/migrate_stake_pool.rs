@@ -0,0 +1,37 @@
+use crate::errors::ErrorCode;
+use crate::utils::resize_account;
+use crate::StakePool;
+use crate::STAKE_POOL_APPENDED_FIELDS_SIZE;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateStakePoolCtx<'info> {
+    /// CHECK: Checked in handler, older pools cannot be deserialized until migrated
+    #[account(mut, owner = crate::id() @ ErrorCode::InvalidStakePool)]
+    stake_pool: UncheckedAccount<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<MigrateStakePoolCtx>) -> Result<()> {
+    let stake_pool_info = ctx.accounts.stake_pool.to_account_info();
+    if stake_pool_info.try_borrow_data()?[..8] != StakePool::DISCRIMINATOR {
+        return Err(error!(ErrorCode::InvalidStakePool));
+    }
+    if StakePool::try_deserialize(&mut stake_pool_info.try_borrow_data()?.as_ref()).is_ok() {
+        return Ok(());
+    }
+
+    resize_account(
+        &stake_pool_info,
+        stake_pool_info.data_len().checked_add(STAKE_POOL_APPENDED_FIELDS_SIZE).expect("Add error"),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    if StakePool::try_deserialize(&mut stake_pool_info.try_borrow_data()?.as_ref()).is_err() {
+        return Err(error!(ErrorCode::InvalidStakePool));
+    }
+    Ok(())
+}
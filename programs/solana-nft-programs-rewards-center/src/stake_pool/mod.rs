@@ -7,5 +7,11 @@ pub use init_pool::*;
 pub mod update_pool;
 pub use update_pool::*;
 
+pub mod migrate_stake_pool;
+pub use migrate_stake_pool::*;
+
+pub mod advance_season;
+pub use advance_season::*;
+
 pub mod close_stake_pool;
 pub use close_stake_pool::*;
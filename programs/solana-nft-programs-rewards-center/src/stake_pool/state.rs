@@ -1,11 +1,17 @@
+use crate::errors::ErrorCode;
+use crate::StakeSetRule;
+use crate::TraitRule;
 use anchor_lang::prelude::*;
 
 pub const STAKE_POOL_DEFAULT_SIZE: usize = 8 + 1 + 32 + 8 + 1 + 32 + 32 + 1 + 24;
 pub const STAKE_POOL_PREFIX: &str = "stake-pool";
+// zeroed encoding of the fields appended after identifier, pools created before them end at identifier
+pub const STAKE_POOL_APPENDED_FIELDS_SIZE: usize = 1 + 1 + 1 + 1 + 1 + 4 + 4 + 1 + 1 + 1 + 4 + 1 + 4 + 4 + 4 + 8 + 1 + 1 + 1 + 1;
 #[account]
 pub struct StakePool {
     pub bump: u8,
     pub authority: Pubkey,
+    // staked entries, a fungible entry counts once however many times it is topped up
     pub total_staked: u32,
     pub reset_on_unstake: bool,
     pub cooldown_seconds: Option<u32>,
@@ -17,4 +23,109 @@ pub struct StakePool {
     pub allowed_creators: Vec<Pubkey>,
     pub allowed_collections: Vec<Pubkey>,
     pub identifier: String,
+    pub pass_mint: Option<Pubkey>,
+    pub fee_exempt_mint: Option<Pubkey>,
+    pub fee_exempt_boost_and_claim: bool,
+    pub allowed_mints_root: Option<[u8; 32]>,
+    pub trait_rules_root: Option<[u8; 32]>,
+    pub trait_rules: Vec<TraitRule>,
+    pub denied_mints: u32,
+    pub permit_signer: Option<Pubkey>,
+    pub max_staked: Option<u32>,
+    pub max_staked_per_wallet: Option<u32>,
+    pub lock_tiers: Vec<LockTier>,
+    pub loyalty_ramp: Option<LoyaltyRamp>,
+    pub boost_windows: Vec<BoostWindow>,
+    pub stake_set_rules: Vec<StakeSetRule>,
+    pub season: u32,
+    pub season_started_at: i64,
+    pub stake_seconds_decay: Option<StakeSecondsDecay>,
+    pub warmup_seconds: Option<u32>,
+    pub accrue_during_cooldown: bool,
+    pub swap_stake_basis_points: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct LockTier {
+    pub lock_seconds: u32,
+    pub multiplier_basis_points: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct LoyaltyRamp {
+    pub interval_seconds: u32,
+    pub increment_basis_points: u64,
+    pub max_bonus_basis_points: u64,
+}
+
+// each step up to the cap is a breakpoint when accruing, so bound how many a ramp can have
+pub const MAX_LOYALTY_RAMP_STEPS: u64 = 100;
+
+pub fn assert_loyalty_ramp(loyalty_ramp: Option<LoyaltyRamp>) -> Result<()> {
+    if let Some(loyalty_ramp) = loyalty_ramp {
+        if loyalty_ramp.interval_seconds == 0 || loyalty_ramp.increment_basis_points == 0 {
+            return Err(error!(ErrorCode::InvalidLoyaltyRamp));
+        }
+        let steps = loyalty_ramp
+            .max_bonus_basis_points
+            .checked_add(loyalty_ramp.increment_basis_points - 1)
+            .ok_or(error!(ErrorCode::InvalidLoyaltyRamp))?
+            / loyalty_ramp.increment_basis_points;
+        if steps > MAX_LOYALTY_RAMP_STEPS {
+            return Err(error!(ErrorCode::InvalidLoyaltyRamp));
+        }
+    }
+    Ok(())
+}
+
+// each window start and end is a breakpoint when accruing, so bound how many a pool can have
+pub const MAX_BOOST_WINDOWS: usize = 10;
+
+pub fn assert_boost_windows(boost_windows: &[BoostWindow], current_boost_windows: Option<&Vec<BoostWindow>>) -> Result<()> {
+    if boost_windows.len() > MAX_BOOST_WINDOWS {
+        return Err(error!(ErrorCode::InvalidBoostWindow));
+    }
+    for boost_window in boost_windows {
+        if boost_window.start >= boost_window.end || boost_window.multiplier_basis_points == 0 {
+            return Err(error!(ErrorCode::InvalidBoostWindow));
+        }
+        // a new window starting in the past would change seconds entries have already accrued
+        if let Some(current_boost_windows) = current_boost_windows {
+            if !current_boost_windows.contains(boost_window) && boost_window.start < Clock::get().unwrap().unix_timestamp {
+                return Err(error!(ErrorCode::InvalidBoostWindow));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum StakeSecondsDecayKind {
+    // halves every period_seconds
+    HalfLife = 0,
+    // reaches zero after period_seconds
+    Linear,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct StakeSecondsDecay {
+    pub kind: u8,
+    pub period_seconds: u32,
+}
+
+pub fn assert_stake_seconds_decay(stake_seconds_decay: Option<StakeSecondsDecay>) -> Result<()> {
+    if let Some(stake_seconds_decay) = stake_seconds_decay {
+        if stake_seconds_decay.kind > StakeSecondsDecayKind::Linear as u8 || stake_seconds_decay.period_seconds == 0 {
+            return Err(error!(ErrorCode::InvalidStakeSecondsDecay));
+        }
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct BoostWindow {
+    pub start: i64,
+    pub end: i64,
+    pub multiplier_basis_points: u64,
 }
@@ -1,7 +1,16 @@
+use crate::assert_boost_windows;
+use crate::assert_loyalty_ramp;
 use crate::assert_payment_info;
+use crate::assert_stake_seconds_decay;
 use crate::utils::resize_account;
 use crate::Action;
+use crate::BoostWindow;
+use crate::LockTier;
+use crate::LoyaltyRamp;
 use crate::StakePool;
+use crate::StakeSecondsDecay;
+use crate::StakeSetRule;
+use crate::TraitRule;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -16,6 +25,23 @@ pub struct UpdatePoolIx {
     end_date: Option<i64>,
     stake_payment_info: Pubkey,
     unstake_payment_info: Pubkey,
+    pass_mint: Option<Pubkey>,
+    fee_exempt_mint: Option<Pubkey>,
+    fee_exempt_boost_and_claim: bool,
+    allowed_mints_root: Option<[u8; 32]>,
+    trait_rules_root: Option<[u8; 32]>,
+    trait_rules: Vec<TraitRule>,
+    permit_signer: Option<Pubkey>,
+    max_staked: Option<u32>,
+    max_staked_per_wallet: Option<u32>,
+    lock_tiers: Vec<LockTier>,
+    loyalty_ramp: Option<LoyaltyRamp>,
+    boost_windows: Vec<BoostWindow>,
+    stake_set_rules: Vec<StakeSetRule>,
+    stake_seconds_decay: Option<StakeSecondsDecay>,
+    warmup_seconds: Option<u32>,
+    accrue_during_cooldown: bool,
+    swap_stake_basis_points: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -35,6 +61,9 @@ pub fn handler(ctx: Context<UpdatePoolCtx>, ix: UpdatePoolIx) -> Result<()> {
 
     assert_payment_info(stake_pool.key(), Action::Stake, ix.stake_payment_info)?;
     assert_payment_info(stake_pool.key(), Action::Unstake, ix.unstake_payment_info)?;
+    assert_loyalty_ramp(ix.loyalty_ramp)?;
+    assert_stake_seconds_decay(ix.stake_seconds_decay)?;
+    assert_boost_windows(&ix.boost_windows, Some(&stake_pool.boost_windows))?;
 
     let new_stake_pool = StakePool {
         bump: stake_pool.bump,
@@ -50,6 +79,26 @@ pub fn handler(ctx: Context<UpdatePoolCtx>, ix: UpdatePoolIx) -> Result<()> {
         allowed_creators: ix.allowed_creators,
         allowed_collections: ix.allowed_collections,
         identifier: stake_pool.identifier.clone(),
+        pass_mint: ix.pass_mint,
+        fee_exempt_mint: ix.fee_exempt_mint,
+        fee_exempt_boost_and_claim: ix.fee_exempt_boost_and_claim,
+        allowed_mints_root: ix.allowed_mints_root,
+        trait_rules_root: ix.trait_rules_root,
+        trait_rules: ix.trait_rules,
+        denied_mints: stake_pool.denied_mints,
+        permit_signer: ix.permit_signer,
+        max_staked: ix.max_staked,
+        max_staked_per_wallet: ix.max_staked_per_wallet,
+        lock_tiers: ix.lock_tiers,
+        loyalty_ramp: ix.loyalty_ramp,
+        boost_windows: ix.boost_windows,
+        stake_set_rules: ix.stake_set_rules,
+        season: stake_pool.season,
+        season_started_at: stake_pool.season_started_at,
+        stake_seconds_decay: ix.stake_seconds_decay,
+        warmup_seconds: ix.warmup_seconds,
+        accrue_during_cooldown: ix.accrue_during_cooldown,
+        swap_stake_basis_points: ix.swap_stake_basis_points,
     };
     let new_space = new_stake_pool.try_to_vec()?.len() + 8;
     stake_pool.set_inner(new_stake_pool);
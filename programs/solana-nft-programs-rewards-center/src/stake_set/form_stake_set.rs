@@ -0,0 +1,115 @@
+use crate::assert_trait_proof;
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::stake_set::stake_mint_collection;
+use crate::stake_set::StakeSet;
+use crate::stake_set::STAKE_SET_DEFAULT_SIZE;
+use crate::stake_set::STAKE_SET_PREFIX;
+use crate::utils::resize_account;
+use crate::StakeEntry;
+use crate::StakePool;
+use crate::TraitProof;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct FormStakeSetIx {
+    pub identifier: u64,
+    pub rule: u8,
+    pub trait_proofs: Vec<TraitProof>,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: FormStakeSetIx)]
+pub struct FormStakeSetCtx<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = STAKE_SET_DEFAULT_SIZE,
+        seeds = [STAKE_SET_PREFIX.as_bytes(), stake_pool.key().as_ref(), user.key().as_ref(), ix.identifier.to_le_bytes().as_ref()],
+        bump,
+    )]
+    stake_set: Box<Account<'info, StakeSet>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+
+    #[account(mut)]
+    user: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<FormStakeSetCtx>, ix: FormStakeSetIx) -> Result<()> {
+    let stake_pool = &ctx.accounts.stake_pool;
+    let rule = match stake_pool.stake_set_rules.get(usize::from(ix.rule)) {
+        Some(rule) => rule,
+        None => return Err(error!(ErrorCode::InvalidStakeSetRule)),
+    };
+    let member_count = if rule.collections.is_empty() { usize::from(rule.size) } else { rule.collections.len() };
+    if member_count == 0 || (rule.collections.is_empty() && rule.trait_match.is_none()) {
+        return Err(error!(ErrorCode::InvalidStakeSetRule));
+    }
+
+    // each member is a stake entry, followed by its mint metadata for collection rules
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let mut unmatched_collections = rule.collections.clone();
+    let mut stake_entries: Vec<Pubkey> = Vec::new();
+    for i in 0..member_count {
+        let stake_entry_info = next_account_info(remaining_accounts)?;
+        let mut stake_entry = match Account::<StakeEntry>::try_from(stake_entry_info) {
+            Ok(stake_entry) => stake_entry,
+            Err(_) => return Err(error!(ErrorCode::InvalidStakeSetMember)),
+        };
+        if stake_entry.pool != stake_pool.key()
+            || stake_entry.last_staker != ctx.accounts.user.key()
+            || stake_entry.amount == 0
+            || stake_entry.cooldown_start_seconds.is_some()
+            || stake_entry.stake_set.is_some()
+            || stake_entries.contains(&stake_entry.key())
+        {
+            return Err(error!(ErrorCode::InvalidStakeSetMember));
+        }
+
+        if rule.collections.is_empty() {
+            let trait_match = rule.trait_match.as_ref().unwrap();
+            let (trait_rules_root, trait_proof) = match (stake_pool.trait_rules_root, ix.trait_proofs.get(i)) {
+                (Some(trait_rules_root), Some(trait_proof)) => (trait_rules_root, trait_proof),
+                _ => return Err(error!(ErrorCode::InvalidTraitProof)),
+            };
+            assert_trait_proof(trait_rules_root, stake_entry.stake_mint, trait_proof)?;
+            if !trait_proof.traits.contains(trait_match) {
+                return Err(error!(ErrorCode::InvalidStakeSetMember));
+            }
+        } else {
+            let stake_mint_metadata_info = next_account_info(remaining_accounts)?;
+            let collection = stake_mint_collection(stake_entry.stake_mint, stake_mint_metadata_info)?;
+            match unmatched_collections.iter().position(|c| *c == collection) {
+                Some(index) => unmatched_collections.swap_remove(index),
+                None => return Err(error!(ErrorCode::InvalidStakeSetMember)),
+            };
+        }
+
+        // accrue at the current rate so the set multiplier only applies from now
+        increment_total_stake_seconds(&mut stake_entry, stake_pool)?;
+        stake_entry.stake_set = Some(ctx.accounts.stake_set.key());
+        stake_entry.stake_set_multiplier_basis_points = Some(rule.multiplier_basis_points);
+        stake_entry.exit(&crate::id())?;
+        stake_entry_fill_zeros(&mut stake_entry)?;
+        stake_entries.push(stake_entry.key());
+    }
+
+    let stake_set = &mut ctx.accounts.stake_set;
+    stake_set.bump = *ctx.bumps.get("stake_set").unwrap();
+    stake_set.pool = stake_pool.key();
+    stake_set.user = ctx.accounts.user.key();
+    stake_set.identifier = ix.identifier;
+    stake_set.multiplier_basis_points = rule.multiplier_basis_points;
+    stake_set.stake_entries = stake_entries;
+
+    let new_space = stake_set.try_to_vec()?.len() + 8;
+    resize_account(
+        &stake_set.to_account_info(),
+        new_space,
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    Ok(())
+}
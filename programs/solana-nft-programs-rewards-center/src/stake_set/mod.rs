@@ -0,0 +1,5 @@
+pub mod state;
+pub use state::*;
+
+pub mod form_stake_set;
+pub use form_stake_set::*;
@@ -0,0 +1,93 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::StakeEntry;
+use crate::StakePool;
+use crate::Trait;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use mpl_token_metadata::accounts::Metadata;
+use mpl_utils::assert_derivation;
+use std::slice::Iter;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct StakeSetRule {
+    // one member from each collection when set, otherwise `size` members sharing `trait_match`
+    pub collections: Vec<Pubkey>,
+    pub trait_match: Option<Trait>,
+    pub size: u8,
+    pub multiplier_basis_points: u64,
+}
+
+pub const STAKE_SET_PREFIX: &str = "stake-set";
+pub const STAKE_SET_DEFAULT_SIZE: usize = 8 + 1 + 32 + 32 + 8 + 8 + 4;
+#[account]
+pub struct StakeSet {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub user: Pubkey,
+    pub identifier: u64,
+    pub multiplier_basis_points: u64,
+    pub stake_entries: Vec<Pubkey>,
+}
+
+pub fn stake_mint_collection(stake_mint: Pubkey, stake_mint_metadata_info: &AccountInfo) -> Result<Pubkey> {
+    assert_derivation(
+        &mpl_token_metadata::ID,
+        stake_mint_metadata_info,
+        &["metadata".to_string().as_bytes(), mpl_token_metadata::ID.as_ref(), stake_mint.as_ref()],
+        error!(ErrorCode::InvalidMintMetadata),
+    )?;
+    if stake_mint_metadata_info.owner.key() != mpl_token_metadata::ID {
+        return Err(error!(ErrorCode::InvalidMintMetadataOwner));
+    }
+    let stake_mint_metadata_data = stake_mint_metadata_info.try_borrow_data().expect("Failed to borrow data");
+    let stake_mint_metadata = Metadata::deserialize(&mut stake_mint_metadata_data.as_ref()).expect("Failed to deserialize metadata");
+    match stake_mint_metadata.collection {
+        Some(collection) if collection.verified => Ok(collection.key),
+        _ => Err(error!(ErrorCode::InvalidStakeSetMember)),
+    }
+}
+
+pub fn dissolve_stake_set<'info>(stake_entry: &mut Account<StakeEntry>, stake_pool: &StakePool, remaining_accounts: &mut Iter<AccountInfo<'info>>, rent_destination: AccountInfo<'info>) -> Result<()> {
+    let stake_set_key = match stake_entry.stake_set {
+        Some(stake_set_key) => stake_set_key,
+        None => return Ok(()),
+    };
+    // the stake set and its other members come before the payment accounts
+    let stake_set_info = next_account_info(remaining_accounts)?;
+    if stake_set_info.key() != stake_set_key {
+        return Err(error!(ErrorCode::InvalidStakeSet));
+    }
+    let stake_set = match Account::<StakeSet>::try_from(stake_set_info) {
+        Ok(stake_set) => stake_set,
+        Err(_) => return Err(error!(ErrorCode::InvalidStakeSet)),
+    };
+    if rent_destination.key() != stake_set.user {
+        return Err(error!(ErrorCode::InvalidStakeSet));
+    }
+
+    for member in stake_set.stake_entries.iter() {
+        if *member == stake_entry.key() {
+            increment_total_stake_seconds(stake_entry, stake_pool)?;
+            stake_entry.stake_set = None;
+            stake_entry.stake_set_multiplier_basis_points = None;
+            continue;
+        }
+        let member_info = next_account_info(remaining_accounts)?;
+        if member_info.key() != *member {
+            return Err(error!(ErrorCode::InvalidStakeSet));
+        }
+        let mut member_stake_entry = match Account::<StakeEntry>::try_from(member_info) {
+            Ok(member_stake_entry) => member_stake_entry,
+            Err(_) => return Err(error!(ErrorCode::InvalidStakeSet)),
+        };
+        increment_total_stake_seconds(&mut member_stake_entry, stake_pool)?;
+        member_stake_entry.stake_set = None;
+        member_stake_entry.stake_set_multiplier_basis_points = None;
+        member_stake_entry.exit(&crate::id())?;
+        stake_entry_fill_zeros(&mut member_stake_entry)?;
+    }
+    stake_set.close(rent_destination)?;
+    Ok(())
+}
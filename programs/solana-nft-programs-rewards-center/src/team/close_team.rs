@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+use crate::team::Team;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+
+#[derive(Accounts)]
+pub struct CloseTeamCtx<'info> {
+    #[account(mut, constraint = team.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    team: Box<Account<'info, Team>>,
+    #[account(mut)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CloseTeamCtx>) -> Result<()> {
+    if ctx.accounts.team.member_count != 0 {
+        return Err(error!(ErrorCode::CannotCloseTeamWithMembers));
+    }
+    ctx.accounts.team.close(ctx.accounts.authority.to_account_info())?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::team::Team;
+use crate::team::TEAM_PREFIX;
+use crate::team::TEAM_SIZE;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(identifier: u64)]
+pub struct InitTeamCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = TEAM_SIZE,
+        seeds = [TEAM_PREFIX.as_bytes(), stake_pool.key().as_ref(), identifier.to_le_bytes().as_ref()],
+        bump,
+    )]
+    team: Box<Account<'info, Team>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitTeamCtx>, identifier: u64) -> Result<()> {
+    let team = &mut ctx.accounts.team;
+    team.bump = *ctx.bumps.get("team").unwrap();
+    team.pool = ctx.accounts.stake_pool.key();
+    team.authority = ctx.accounts.authority.key();
+    team.identifier = identifier;
+    team.member_count = 0;
+    team.total_stake_seconds = 0;
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::team::Team;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct JoinTeamCtx<'info> {
+    #[account(mut, constraint = team.pool == stake_pool.key() @ ErrorCode::InvalidTeam)]
+    team: Box<Account<'info, Team>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = stake_entry.pool == stake_pool.key() @ ErrorCode::InvalidStakePool)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+
+    #[account(constraint = user.key() == stake_entry.last_staker @ ErrorCode::InvalidLastStaker)]
+    user: Signer<'info>,
+    // the team authority approves every member
+    #[account(constraint = team.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<JoinTeamCtx>) -> Result<()> {
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    if stake_entry.team.is_some() {
+        return Err(error!(ErrorCode::StakeEntryAlreadyInTeam));
+    }
+
+    // checkpoint the seconds accrued so far so only time spent in the team counts
+    increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+    stake_entry.team = Some(ctx.accounts.team.key());
+    stake_entry.team_stake_seconds_checkpoint = stake_entry.total_stake_seconds;
+    stake_entry_fill_zeros(stake_entry)?;
+
+    let team = &mut ctx.accounts.team;
+    team.member_count = team.member_count.checked_add(1).expect("Add error");
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::team::sync_team_stake_seconds;
+use crate::team::Team;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LeaveTeamCtx<'info> {
+    #[account(mut, constraint = team.pool == stake_pool.key() @ ErrorCode::InvalidTeam)]
+    team: Box<Account<'info, Team>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = stake_entry.team == Some(team.key()) @ ErrorCode::StakeEntryNotInTeam)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+
+    // anyone can remove an entry that is no longer staked
+    #[account(constraint = user.key() == stake_entry.last_staker || stake_entry.last_staker == Pubkey::default() @ ErrorCode::InvalidLastStaker)]
+    user: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<LeaveTeamCtx>) -> Result<()> {
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    let team = &mut ctx.accounts.team;
+
+    increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+    sync_team_stake_seconds(team, stake_entry);
+    stake_entry.team = None;
+    stake_entry.team_stake_seconds_checkpoint = 0;
+    stake_entry_fill_zeros(stake_entry)?;
+
+    team.member_count = team.member_count.saturating_sub(1);
+    Ok(())
+}
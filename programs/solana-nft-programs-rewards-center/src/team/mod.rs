@@ -0,0 +1,17 @@
+pub mod state;
+pub use state::*;
+
+pub mod init_team;
+pub use init_team::*;
+
+pub mod join_team;
+pub use join_team::*;
+
+pub mod leave_team;
+pub use leave_team::*;
+
+pub mod sync_team_stake_seconds;
+pub use sync_team_stake_seconds::*;
+
+pub mod close_team;
+pub use close_team::*;
@@ -0,0 +1,34 @@
+use crate::StakeEntry;
+use anchor_lang::prelude::*;
+
+pub const TEAM_PREFIX: &str = "team";
+pub const TEAM_SIZE: usize = 8 + std::mem::size_of::<Team>() + 64;
+#[account]
+pub struct Team {
+    pub bump: u8,
+    pub pool: Pubkey,
+    pub authority: Pubkey,
+    pub identifier: u64,
+    pub member_count: u32,
+    pub total_stake_seconds: u128,
+}
+
+pub fn sync_team_stake_seconds(team: &mut Team, stake_entry: &mut StakeEntry) {
+    // only seconds accrued since the last checkpoint count towards the team
+    let seconds_increased = stake_entry
+        .total_stake_seconds
+        .saturating_sub(stake_entry.team_stake_seconds_checkpoint)
+        .saturating_add(stake_entry.team_pending_stake_seconds);
+    team.total_stake_seconds = team.total_stake_seconds.saturating_add(seconds_increased);
+    stake_entry.team_stake_seconds_checkpoint = stake_entry.total_stake_seconds;
+    stake_entry.team_pending_stake_seconds = 0;
+}
+
+pub fn defer_team_stake_seconds(stake_entry: &mut StakeEntry) {
+    // called before total_stake_seconds is reset so unsynced seconds are not lost
+    if stake_entry.team.is_some() {
+        let seconds_increased = stake_entry.total_stake_seconds.saturating_sub(stake_entry.team_stake_seconds_checkpoint);
+        stake_entry.team_pending_stake_seconds = stake_entry.team_pending_stake_seconds.saturating_add(seconds_increased);
+    }
+    stake_entry.team_stake_seconds_checkpoint = 0;
+}
@@ -0,0 +1,27 @@
+use crate::errors::ErrorCode;
+use crate::increment_total_stake_seconds;
+use crate::stake_entry_fill_zeros;
+use crate::team::sync_team_stake_seconds;
+use crate::team::Team;
+use crate::StakeEntry;
+use crate::StakePool;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SyncTeamStakeSecondsCtx<'info> {
+    #[account(mut, constraint = team.pool == stake_pool.key() @ ErrorCode::InvalidTeam)]
+    team: Box<Account<'info, Team>>,
+    stake_pool: Box<Account<'info, StakePool>>,
+    #[account(mut, constraint = stake_entry.team == Some(team.key()) @ ErrorCode::StakeEntryNotInTeam)]
+    stake_entry: Box<Account<'info, StakeEntry>>,
+}
+
+pub fn handler(ctx: Context<SyncTeamStakeSecondsCtx>) -> Result<()> {
+    let stake_entry = &mut ctx.accounts.stake_entry;
+    if stake_entry.last_staker != Pubkey::default() && stake_entry.cooldown_start_seconds.is_none() {
+        increment_total_stake_seconds(stake_entry, &ctx.accounts.stake_pool)?;
+    }
+    sync_team_stake_seconds(&mut ctx.accounts.team, stake_entry);
+    stake_entry_fill_zeros(stake_entry)?;
+    Ok(())
+}
@@ -2,6 +2,7 @@ use std::cmp::Ordering;
 
 use anchor_lang::prelude::*;
 use anchor_lang::Result;
+use solana_program::keccak;
 use solana_program::program::invoke;
 use solana_program::system_instruction::transfer;
 
@@ -26,6 +27,29 @@ pub fn resize_account<'info>(account_info: &AccountInfo<'info>, new_space: usize
         }
         Ordering::Equal => {}
     }
-    account_info.realloc(new_space, false)?;
+    // zero any added space so fields appended to older layouts read as defaults
+    account_info.realloc(new_space, true)?;
     Ok(())
 }
+
+// accounts created before rent payers were recorded refund the authority
+pub fn rent_payer_or_authority(rent_payer: Pubkey, authority: Pubkey) -> Pubkey {
+    if rent_payer == Pubkey::default() {
+        authority
+    } else {
+        rent_payer
+    }
+}
+
+pub fn merkle_proof_is_valid(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash = leaf;
+    for proof_element in proof {
+        // pairs are hashed in sorted order so proofs do not need to encode left/right positions
+        computed_hash = if computed_hash <= *proof_element {
+            keccak::hashv(&[&computed_hash, proof_element]).0
+        } else {
+            keccak::hashv(&[proof_element, &computed_hash]).0
+        };
+    }
+    computed_hash == root
+}
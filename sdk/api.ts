@@ -6,7 +6,7 @@ import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountMeta, Connection, PublicKey } from "@solana/web3.js";
 import {
   ComputeBudgetProgram,
   SystemProgram,
@@ -30,7 +30,11 @@ import {
   fetchIdlAccount,
   fetchIdlAccountDataById,
 } from "./accounts";
-import { remainingAccountsForAuthorization } from "./authorization";
+import {
+  remainingAccountsForAuthorization,
+  remainingAccountsForFeeExemption,
+  remainingAccountsForPass,
+} from "./authorization";
 import type { PaymentShare } from "./constants";
 import { rewardsCenterProgram } from "./constants";
 import {
@@ -45,6 +49,7 @@ import {
   findStakeEntryId,
   findStakePoolId,
   findUserEscrowId,
+  findUserStakeRegistryId,
 } from "./pda";
 import {
   findMintEditionId,
@@ -70,14 +75,34 @@ export const stake = async (
     tokenAccountId?: PublicKey;
     amount?: BN;
     fungible?: boolean;
+    mintProof?: number[][];
+    traitProof?: {
+      traits: { traitType: string; value: string }[];
+      proof: number[][];
+    };
+    lockTier?: number;
+    passTokenAccountId?: PublicKey;
   }[],
 ) => {
   const stakePoolId = findStakePoolId(stakePoolIdentifier);
   const mints = mintInfos.map(
-    ({ mintId, tokenAccountId, amount, fungible }) => {
+    ({
+      mintId,
+      tokenAccountId,
+      amount,
+      fungible,
+      mintProof,
+      traitProof,
+      lockTier,
+      passTokenAccountId,
+    }) => {
       return {
         mintId,
         amount,
+        passTokenAccountId,
+        mintProof: mintProof ?? [],
+        traitProof: traitProof ?? null,
+        lockTier: lockTier ?? null,
         stakeEntryId: findStakeEntryId(
           stakePoolId,
           mintId,
@@ -106,8 +131,23 @@ export const stake = async (
     "paymentInfo",
   );
 
+  const feeExemptionAccounts = await remainingAccountsForFeeExemption(
+    connection,
+    stakePoolData,
+    wallet.publicKey,
+  );
+
   const txs: Transaction[] = [];
-  for (const { mintId, mintTokenAccountId, stakeEntryId, amount } of mints) {
+  for (const {
+    mintId,
+    mintTokenAccountId,
+    stakeEntryId,
+    amount,
+    mintProof,
+    traitProof,
+    lockTier,
+    passTokenAccountId,
+  } of mints) {
     const tx = new Transaction();
     const metadataId = findMintMetadataId(mintId);
     const mintManagerId = findMintManagerId(mintId);
@@ -122,10 +162,11 @@ export const stake = async (
       stakePoolData,
       mintId,
       metadataInfo ?? null,
+      mintProof,
     );
     if (!accountDataById[stakeEntryId.toString()]) {
       const ix = await rewardsCenterProgram(connection, wallet)
-        .methods.initEntry(wallet.publicKey)
+        .methods.initEntry(wallet.publicKey, mintProof, traitProof)
         .accounts({
           stakeEntry: stakeEntryId,
           stakePool: stakePoolId,
@@ -140,9 +181,20 @@ export const stake = async (
     }
 
     const userEscrowId = findUserEscrowId(wallet.publicKey);
+    const userStakeRegistryId = findUserStakeRegistryId(
+      stakePoolId,
+      wallet.publicKey,
+    );
 
     const remainingAccounts = [
       ...authorizationAccounts,
+      ...(await remainingAccountsForPass(
+        connection,
+        stakePoolData,
+        wallet.publicKey,
+        passTokenAccountId,
+      )),
+      ...feeExemptionAccounts,
       ...withRemainingAccountsForPaymentInfoSync(
         tx,
         wallet.publicKey,
@@ -155,7 +207,12 @@ export const stake = async (
         mintManagerAccountInfo,
       )[0];
       const stakeIx = await rewardsCenterProgram(connection, wallet)
-        .methods.stakeCcs(new BN(amount ?? 1))
+        .methods.stakeCcs(
+          new BN(amount ?? 1),
+          mintProof,
+          traitProof,
+          lockTier,
+        )
         .accounts({
           stakePool: stakePoolId,
           stakeEntry: stakeEntryId,
@@ -165,6 +222,7 @@ export const stake = async (
           stakeMintManagerRuleset: mintManager.ruleset,
           user: wallet.publicKey,
           userEscrow: userEscrowId,
+          userStakeRegistry: userStakeRegistryId,
           userStakeMintTokenAccount: mintTokenAccountId,
           creatorStandardProgram: CREATOR_STANDARD_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -185,7 +243,7 @@ export const stake = async (
         }),
       );
       const stakeIx = await rewardsCenterProgram(connection, wallet)
-        .methods.stakePnft()
+        .methods.stakePnft(mintProof, traitProof, lockTier)
         .accountsStrict({
           stakePool: stakePoolId,
           stakeEntry: stakeEntryId,
@@ -197,6 +255,7 @@ export const stake = async (
             metadataInfo?.programmableConfig?.ruleSet ?? METADATA_PROGRAM_ID,
           user: wallet.publicKey,
           userEscrow: userEscrowId,
+          userStakeRegistry: userStakeRegistryId,
           userStakeMintTokenAccount: mintTokenAccountId,
           tokenMetadataProgram: METADATA_PROGRAM_ID,
           sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
@@ -210,7 +269,12 @@ export const stake = async (
     } else {
       const editionId = findMintEditionId(mintId);
       const stakeIx = await rewardsCenterProgram(connection, wallet)
-        .methods.stakeEdition(new BN(amount ?? 1))
+        .methods.stakeEdition(
+          new BN(amount ?? 1),
+          mintProof,
+          traitProof,
+          lockTier,
+        )
         .accounts({
           stakePool: stakePoolId,
           stakeEntry: stakeEntryId,
@@ -219,6 +283,7 @@ export const stake = async (
           stakeMintMetadata: metadataId,
           user: wallet.publicKey,
           userEscrow: userEscrowId,
+          userStakeRegistry: userStakeRegistryId,
           userStakeMintTokenAccount: mintTokenAccountId,
           tokenMetadataProgram: METADATA_PROGRAM_ID,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -297,10 +362,26 @@ export const unstake = async (
   ]);
   accountDataById = { ...accountDataById, ...accountDataById2 };
 
+  const feeExemptionAccounts = await remainingAccountsForFeeExemption(
+    connection,
+    stakePoolData,
+    wallet.publicKey,
+  );
+  const claimFeeExemptionAccounts = await remainingAccountsForFeeExemption(
+    connection,
+    stakePoolData,
+    wallet.publicKey,
+    true,
+  );
+
   const txs: Transaction[] = [];
   for (const { mintId, stakeEntryId, rewardEntryIds } of mints) {
     const tx = new Transaction();
     const userEscrowId = findUserEscrowId(wallet.publicKey);
+    const userStakeRegistryId = findUserStakeRegistryId(
+      stakePoolId,
+      wallet.publicKey,
+    );
     const userAtaId = getAssociatedTokenAddressSync(mintId, wallet.publicKey);
     const stakeEntry = accountDataById[stakeEntryId.toString()];
 
@@ -316,6 +397,7 @@ export const unstake = async (
       const ix = await rewardsCenterProgram(connection, wallet)
         .methods.updateTotalStakeSeconds()
         .accounts({
+          stakePool: stakePoolId,
           stakeEntry: stakeEntryId,
           updater: wallet.publicKey,
         })
@@ -360,7 +442,7 @@ export const unstake = async (
             tx.add(ix);
           }
 
-          const remainingAccountsForPayment = [];
+          const remainingAccountsForPayment = [...claimFeeExemptionAccounts];
           const claimRewardsPaymentInfo =
             accountDataById[
               rewardDistributorData.parsed.claimRewardsPaymentInfo.toString()
@@ -396,7 +478,22 @@ export const unstake = async (
       }
     }
 
-    const remainingAccounts = [];
+    const remainingAccounts: AccountMeta[] = [];
+    if (stakeEntry?.type === "stakeEntry" && stakeEntry.parsed.stakeSet) {
+      // unstaking a member dissolves its stake set
+      const stakeSet = await fetchIdlAccount(
+        connection,
+        stakeEntry.parsed.stakeSet,
+        "stakeSet",
+      );
+      remainingAccounts.push(
+        { pubkey: stakeSet.pubkey, isSigner: false, isWritable: true },
+        ...stakeSet.parsed.stakeEntries
+          .filter((id) => !id.equals(stakeEntryId))
+          .map((id) => ({ pubkey: id, isSigner: false, isWritable: true })),
+      );
+    }
+    remainingAccounts.push(...feeExemptionAccounts);
     const unstakePaymentInfo =
       accountDataById[stakePoolData.parsed.unstakePaymentInfo.toString()];
     if (unstakePaymentInfo && unstakePaymentInfo.type === "paymentInfo") {
@@ -424,6 +521,7 @@ export const unstake = async (
           stakeMintManager: mintManagerId,
           user: wallet.publicKey,
           userEscrow: userEscrowId,
+          userStakeRegistry: userStakeRegistryId,
           userStakeMintTokenAccount: userAtaId,
           creatorStandardProgram: CREATOR_STANDARD_PROGRAM_ID,
         })
@@ -451,7 +549,9 @@ export const unstake = async (
             metadata?.programmableConfig?.ruleSet ?? METADATA_PROGRAM_ID,
           user: wallet.publicKey,
           userEscrow: userEscrowId,
+          userStakeRegistry: userStakeRegistryId,
           userStakeMintTokenAccount: userAtaId,
+          leaderboard: null,
           tokenMetadataProgram: METADATA_PROGRAM_ID,
           sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -472,6 +572,7 @@ export const unstake = async (
           stakeMintEdition: editionId,
           user: wallet.publicKey,
           userEscrow: userEscrowId,
+          userStakeRegistry: userStakeRegistryId,
           userStakeMintTokenAccount: userAtaId,
           tokenMetadataProgram: METADATA_PROGRAM_ID,
         })
@@ -522,12 +623,17 @@ export const claimRewards = async (
   });
 
   let accountDataById = await fetchIdlAccountDataById(connection, [
+    stakePoolId,
     ...(rewardDistributorIds ?? []),
     ...mints.map((m) => m.rewardEntryIds ?? []).flat(),
     ...(claimingRewardsForUsers
       ? mints.map((m) => findStakeEntryId(stakePoolId, m.mintId)).flat()
       : []),
   ]);
+  const stakePoolData = accountDataById[stakePoolId.toString()];
+  if (!stakePoolData?.parsed || stakePoolData.type !== "stakePool") {
+    throw "Stake pool not found";
+  }
   const claimRewardsPaymentInfoIds = rewardDistributorIds?.map((id) => {
     const rewardDistributorData = accountDataById[id.toString()];
     if (
@@ -555,6 +661,7 @@ export const claimRewards = async (
       const ix = await rewardsCenterProgram(connection, wallet)
         .methods.updateTotalStakeSeconds()
         .accounts({
+          stakePool: stakePoolId,
           stakeEntry: stakeEntryId,
           updater: wallet.publicKey,
         })
@@ -612,7 +719,14 @@ export const claimRewards = async (
             tx.add(ix);
           }
 
-          const remainingAccountsForPayment = [];
+          const remainingAccountsForPayment = [
+            ...(await remainingAccountsForFeeExemption(
+              connection,
+              stakePoolData,
+              userRewardMintTokenAccountOwnerId,
+              true,
+            )),
+          ];
           const unstakePaymentInfo =
             accountDataById[
               rewardDistributorData.parsed.claimRewardsPaymentInfo.toString()
@@ -693,6 +807,7 @@ export const claimRewardReceipt = async (
   const ix = await rewardsCenterProgram(connection, wallet)
     .methods.updateTotalStakeSeconds()
     .accounts({
+      stakePool: stakePoolId,
       stakeEntry: stakeEntryId,
       updater: wallet.publicKey,
     })
@@ -710,12 +825,21 @@ export const claimRewardReceipt = async (
       .instruction();
     tx.add(ix);
   }
+  const paymentTargets = (
+    receiptManagerData.parsed.paymentShares as PaymentShare[]
+  ).map((p) => p.address);
+  const paymentTargetDataById = await fetchIdlAccountDataById(
+    connection,
+    paymentTargets,
+  );
   const remainingAccountsForPayment = withRemainingAccountsForPayment(
     tx,
     wallet.publicKey,
     receiptManagerData.parsed.paymentMint,
-    (receiptManagerData.parsed.paymentShares as PaymentShare[]).map(
-      (p) => p.address,
+    paymentTargets,
+    paymentTargets.filter(
+      (id) =>
+        paymentTargetDataById[id.toString()]?.type === "rewardDistributor",
     ),
   );
 
@@ -736,6 +860,12 @@ export const claimRewardReceipt = async (
     })
     .remainingAccounts([
       ...remainingAccountsForPayment,
+      ...(await remainingAccountsForFeeExemption(
+        connection,
+        stakePoolData,
+        stakeEntryData.parsed.lastStaker,
+        true,
+      )),
       ...remainingAccountsForAction,
     ])
     .instruction();
@@ -777,8 +907,18 @@ export const boost = async (
   );
 
   const accountDataById = await fetchIdlAccountDataById(connection, [
+    stakePoolId,
+    stakeEntryId,
     stakeBoosterId,
   ]);
+  const stakePoolData = accountDataById[stakePoolId.toString()];
+  if (!stakePoolData?.parsed || stakePoolData.type !== "stakePool") {
+    throw "Stake pool not found";
+  }
+  const stakeEntryData = accountDataById[stakeEntryId.toString()];
+  if (!stakeEntryData?.parsed || stakeEntryData.type !== "stakeEntry") {
+    throw "Stake entry not found";
+  }
   const stakeBoosterData = accountDataById[stakeBoosterId.toString()];
   if (!stakeBoosterData?.parsed || stakeBoosterData.type !== "stakeBooster") {
     throw "Stake booster not found";
@@ -788,6 +928,7 @@ export const boost = async (
   const ix = await rewardsCenterProgram(connection, wallet)
     .methods.updateTotalStakeSeconds()
     .accounts({
+      stakePool: stakePoolId,
       stakeEntry: stakeEntryId,
       updater: wallet.publicKey,
     })
@@ -819,6 +960,12 @@ export const boost = async (
     })
     .remainingAccounts([
       ...remainingAccountsForPayment,
+      ...(await remainingAccountsForFeeExemption(
+        connection,
+        stakePoolData,
+        stakeEntryData.parsed.lastStaker,
+        true,
+      )),
       ...remainingAccountsForAction,
     ])
     .instruction();
@@ -1,14 +1,27 @@
 import type { Metadata } from "@metaplex-foundation/mpl-token-metadata";
-import type { PublicKey } from "@solana/web3.js";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { AccountMeta, Connection, PublicKey } from "@solana/web3.js";
+import { tryNull } from "@solana-nft-programs/common";
 
 import type { StakePool } from "./constants";
-import { findStakeAuthorizationRecordId } from "./pda";
+import { REWARDS_CENTER_ADDRESS } from "./constants";
+import { findStakeAuthorizationRecordId, findStakeDenyRecordId } from "./pda";
+import { findMintMetadataId } from "./utils";
 
 export const remainingAccountsForAuthorization = (
   stakePool: Pick<StakePool, "parsed" | "pubkey">,
   mintId: PublicKey,
   mintMetadata: Metadata | null,
+  mintProof?: number[][],
 ) => {
+  const remainingAccounts: AccountMeta[] = [];
+  if (stakePool.parsed.deniedMints > 0) {
+    remainingAccounts.push({
+      pubkey: findStakeDenyRecordId(stakePool.pubkey, mintId),
+      isSigner: false,
+      isWritable: false,
+    });
+  }
   if (
     stakePool.parsed.requiresAuthorization &&
     !mintMetadata?.data.creators?.some((c) =>
@@ -21,15 +34,79 @@ export const remainingAccountsForAuthorization = (
       stakePool.parsed.allowedCollections
         .map((c) => c.toString())
         .includes(mintMetadata?.collection?.key?.toString())
-    )
+    ) &&
+    !(stakePool.parsed.allowedMintsRoot && mintProof && mintProof.length > 0)
   ) {
+    remainingAccounts.push({
+      pubkey: findStakeAuthorizationRecordId(stakePool.pubkey, mintId),
+      isSigner: false,
+      isWritable: false,
+    });
+  }
+  return remainingAccounts;
+};
+
+const remainingAccountsForHeldPass = async (
+  connection: Connection,
+  passMint: PublicKey,
+  tokenAccountId: PublicKey,
+): Promise<AccountMeta[]> => {
+  const remainingAccounts: AccountMeta[] = [
+    { pubkey: tokenAccountId, isSigner: false, isWritable: false },
+  ];
+  // a token other than the pass mint is proven through its collection metadata
+  const tokenAccount = await tryNull(getAccount(connection, tokenAccountId));
+  if (tokenAccount && !tokenAccount.mint.equals(passMint)) {
+    remainingAccounts.push({
+      pubkey: findMintMetadataId(tokenAccount.mint),
+      isSigner: false,
+      isWritable: false,
+    });
+  }
+  return remainingAccounts;
+};
+
+export const remainingAccountsForPass = async (
+  connection: Connection,
+  stakePool: Pick<StakePool, "parsed">,
+  holder: PublicKey,
+  passTokenAccountId?: PublicKey,
+): Promise<AccountMeta[]> => {
+  if (!stakePool.parsed.passMint) return [];
+  return remainingAccountsForHeldPass(
+    connection,
+    stakePool.parsed.passMint,
+    passTokenAccountId ??
+      getAssociatedTokenAddressSync(stakePool.parsed.passMint, holder, true),
+  );
+};
+
+export const remainingAccountsForFeeExemption = async (
+  connection: Connection,
+  stakePool: Pick<StakePool, "parsed">,
+  holder: PublicKey,
+  boostOrClaim = false,
+  feeExemptTokenAccountId?: PublicKey,
+): Promise<AccountMeta[]> => {
+  if (!stakePool.parsed.feeExemptMint) return [];
+  if (boostOrClaim && !stakePool.parsed.feeExemptBoostAndClaim) return [];
+  const tokenAccountId =
+    feeExemptTokenAccountId ??
+    getAssociatedTokenAddressSync(
+      stakePool.parsed.feeExemptMint,
+      holder,
+      true,
+    );
+  // without a held token the slot is filled with the program id and the fee is paid
+  const tokenAccount = await tryNull(getAccount(connection, tokenAccountId));
+  if (!tokenAccount || Number(tokenAccount.amount) === 0) {
     return [
-      {
-        pubkey: findStakeAuthorizationRecordId(stakePool.pubkey, mintId),
-        isSigner: false,
-        isWritable: false,
-      },
+      { pubkey: REWARDS_CENTER_ADDRESS, isSigner: false, isWritable: false },
     ];
   }
-  return [];
+  return remainingAccountsForHeldPass(
+    connection,
+    stakePool.parsed.feeExemptMint,
+    tokenAccountId,
+  );
 };
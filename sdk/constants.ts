@@ -40,6 +40,12 @@ export type StakeBooster = IdlAccountData<"stakeBooster">;
 export type StakeAuthorizationRecord =
   IdlAccountData<"stakeAuthorizationRecord">;
 export type PaymentInfo = IdlAccountData<"paymentInfo">;
+export type StakeDenyRecord = IdlAccountData<"stakeDenyRecord">;
+export type UserStakeRegistry = IdlAccountData<"userStakeRegistry">;
+export type RewardBonusRule = IdlAccountData<"rewardBonusRule">;
+export type StakeSet = IdlAccountData<"stakeSet">;
+export type Team = IdlAccountData<"team">;
+export type Leaderboard = IdlAccountData<"leaderboard">;
 
 export type PaymentShare = {
   address: PublicKey;
@@ -63,6 +63,43 @@ export type SolanaNftProgramsRewardsCenter = {
         },
       ];
     },
+    {
+      name: "migrateStakePool";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "advanceSeason";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+      ];
+      args: [];
+    },
     {
       name: "closeStakePool";
       accounts: [
@@ -118,11 +155,35 @@ export type SolanaNftProgramsRewardsCenter = {
           name: "user";
           type: "publicKey";
         },
+        {
+          name: "mintProof";
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          };
+        },
+        {
+          name: "traitProof";
+          type: {
+            option: {
+              defined: "TraitProof";
+            };
+          };
+        },
       ];
     },
     {
       name: "updateTotalStakeSeconds";
       accounts: [
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "stakeEntry";
           isMut: true;
@@ -133,6 +194,12 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: true;
         },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [];
     },
@@ -265,6 +332,36 @@ export type SolanaNftProgramsRewardsCenter = {
         },
       ];
     },
+    {
+      name: "addStakeEntryTimedMultiplier";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+      ];
+      args: [
+        {
+          name: "multiplierBasisPoints";
+          type: "u64";
+        },
+        {
+          name: "expiresAt";
+          type: "i64";
+        },
+      ];
+    },
     {
       name: "resizeStakeEntry";
       accounts: [
@@ -299,6 +396,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "rentPayer";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "authority";
           isMut: true;
@@ -307,6 +409,115 @@ export type SolanaNftProgramsRewardsCenter = {
       ];
       args: [];
     },
+    {
+      name: "closeUserEscrow";
+      accounts: [
+        {
+          name: "userEscrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "cancelCooldown";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "swapStake";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "fromStakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "toStakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "sysvarInstructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "backfillUserStakeRegistry";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
     {
       name: "stakeEdition";
       accounts: [
@@ -345,6 +556,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userStakeMintTokenAccount";
           isMut: true;
@@ -371,6 +587,31 @@ export type SolanaNftProgramsRewardsCenter = {
           name: "amount";
           type: "u64";
         },
+        {
+          name: "mintProof";
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          };
+        },
+        {
+          name: "traitProof";
+          type: {
+            option: {
+              defined: "TraitProof";
+            };
+          };
+        },
+        {
+          name: "lockTier";
+          type: {
+            option: "u8";
+          };
+        },
       ];
     },
     {
@@ -406,6 +647,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userStakeMintTokenAccount";
           isMut: true;
@@ -426,6 +672,12 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [];
     },
@@ -472,6 +724,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userStakeMintTokenAccount";
           isMut: true;
@@ -498,6 +755,31 @@ export type SolanaNftProgramsRewardsCenter = {
           name: "amount";
           type: "u64";
         },
+        {
+          name: "mintProof";
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          };
+        },
+        {
+          name: "traitProof";
+          type: {
+            option: {
+              defined: "TraitProof";
+            };
+          };
+        },
+        {
+          name: "lockTier";
+          type: {
+            option: "u8";
+          };
+        },
       ];
     },
     {
@@ -533,6 +815,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userStakeMintTokenAccount";
           isMut: true;
@@ -553,6 +840,12 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [];
     },
@@ -604,6 +897,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userStakeMintTokenAccount";
           isMut: true;
@@ -635,19 +933,45 @@ export type SolanaNftProgramsRewardsCenter = {
           isSigner: false;
         },
       ];
-      args: [];
-    },
-    {
-      name: "unstakePnft";
-      accounts: [
+      args: [
         {
-          name: "stakePool";
-          isMut: true;
-          isSigner: false;
+          name: "mintProof";
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ];
+            };
+          };
         },
         {
-          name: "stakeEntry";
-          isMut: true;
+          name: "traitProof";
+          type: {
+            option: {
+              defined: "TraitProof";
+            };
+          };
+        },
+        {
+          name: "lockTier";
+          type: {
+            option: "u8";
+          };
+        },
+      ];
+    },
+    {
+      name: "unstakePnft";
+      accounts: [
+        {
+          name: "stakePool";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: true;
           isSigner: false;
         },
         {
@@ -685,6 +1009,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "userStakeRegistry";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "userStakeMintTokenAccount";
           isMut: true;
@@ -715,29 +1044,30 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
       ];
       args: [];
     },
     {
-      name: "authorizeMint";
+      name: "formStakeSet";
       accounts: [
         {
-          name: "stakePool";
+          name: "stakeSet";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "stakeAuthorizationRecord";
-          isMut: true;
+          name: "stakePool";
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "payer";
+          name: "user";
           isMut: true;
           isSigner: true;
         },
@@ -749,48 +1079,29 @@ export type SolanaNftProgramsRewardsCenter = {
       ];
       args: [
         {
-          name: "mint";
-          type: "publicKey";
-        },
-      ];
-    },
-    {
-      name: "deauthorizeMint";
-      accounts: [
-        {
-          name: "stakePool";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "stakeAuthorizationRecord";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "authority";
-          isMut: true;
-          isSigner: true;
+          name: "ix";
+          type: {
+            defined: "FormStakeSetIx";
+          };
         },
       ];
-      args: [];
     },
     {
-      name: "initStakeBooster";
+      name: "initTeam";
       accounts: [
         {
-          name: "stakeBooster";
+          name: "team";
           isMut: true;
           isSigner: false;
         },
         {
           name: "stakePool";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
           name: "authority";
-          isMut: true;
+          isMut: false;
           isSigner: true;
         },
         {
@@ -806,52 +1117,53 @@ export type SolanaNftProgramsRewardsCenter = {
       ];
       args: [
         {
-          name: "ix";
-          type: {
-            defined: "InitStakeBoosterIx";
-          };
+          name: "identifier";
+          type: "u64";
         },
       ];
     },
     {
-      name: "updateStakeBooster";
+      name: "joinTeam";
       accounts: [
         {
-          name: "stakeBooster";
+          name: "team";
           isMut: true;
           isSigner: false;
         },
         {
           name: "stakePool";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
-          name: "authority";
+          name: "stakeEntry";
           isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
           isSigner: true;
         },
-      ];
-      args: [
         {
-          name: "ix";
-          type: {
-            defined: "UpdateStakeBoosterIx";
-          };
+          name: "authority";
+          isMut: false;
+          isSigner: true;
         },
       ];
+      args: [];
     },
     {
-      name: "boostStakeEntry";
+      name: "leaveTeam";
       accounts: [
         {
-          name: "stakeBooster";
+          name: "team";
           isMut: true;
           isSigner: false;
         },
         {
           name: "stakePool";
-          isMut: true;
+          isMut: false;
           isSigner: false;
         },
         {
@@ -860,30 +1172,39 @@ export type SolanaNftProgramsRewardsCenter = {
           isSigner: false;
         },
         {
-          name: "stakeMint";
+          name: "user";
           isMut: false;
-          isSigner: false;
-        },
-      ];
-      args: [
-        {
-          name: "ix";
-          type: {
-            defined: "BoostStakeEntryIx";
-          };
+          isSigner: true;
         },
       ];
+      args: [];
     },
     {
-      name: "closeStakeBooster";
+      name: "syncTeamStakeSeconds";
       accounts: [
         {
-          name: "stakeBooster";
+          name: "team";
           isMut: true;
           isSigner: false;
         },
         {
           name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "closeTeam";
+      accounts: [
+        {
+          name: "team";
           isMut: true;
           isSigner: false;
         },
@@ -896,10 +1217,10 @@ export type SolanaNftProgramsRewardsCenter = {
       args: [];
     },
     {
-      name: "initReceiptManager";
+      name: "initLeaderboard";
       accounts: [
         {
-          name: "receiptManager";
+          name: "leaderboard";
           isMut: true;
           isSigner: false;
         },
@@ -908,6 +1229,11 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
         {
           name: "payer";
           isMut: true;
@@ -923,42 +1249,51 @@ export type SolanaNftProgramsRewardsCenter = {
         {
           name: "ix";
           type: {
-            defined: "InitReceiptManagerIx";
+            defined: "InitLeaderboardIx";
           };
         },
       ];
     },
     {
-      name: "updateReceiptManager";
+      name: "updateLeaderboard";
       accounts: [
         {
-          name: "receiptManager";
+          name: "leaderboard";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authority";
+          name: "stakePool";
           isMut: false;
-          isSigner: true;
+          isSigner: false;
         },
-      ];
-      args: [
         {
-          name: "ix";
-          type: {
-            defined: "UpdateReceiptManagerIx";
-          };
+          name: "stakeEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardEntry";
+          isMut: false;
+          isSigner: false;
+          isOptional: true;
         },
       ];
+      args: [];
     },
     {
-      name: "closeReceiptManager";
+      name: "closeLeaderboard";
       accounts: [
         {
-          name: "receiptManager";
+          name: "leaderboard";
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "authority";
           isMut: true;
@@ -968,22 +1303,22 @@ export type SolanaNftProgramsRewardsCenter = {
       args: [];
     },
     {
-      name: "initRewardReceipt";
+      name: "authorizeMint";
       accounts: [
         {
-          name: "rewardReceipt";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "receiptManager";
-          isMut: false;
+          name: "stakeAuthorizationRecord";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "stakeEntry";
-          isMut: false;
-          isSigner: false;
+          name: "authority";
+          isMut: true;
+          isSigner: true;
         },
         {
           name: "payer";
@@ -996,39 +1331,35 @@ export type SolanaNftProgramsRewardsCenter = {
           isSigner: false;
         },
       ];
-      args: [];
-    },
-    {
-      name: "closeRewardReceipt";
-      accounts: [
+      args: [
         {
-          name: "rewardReceipt";
-          isMut: true;
-          isSigner: false;
+          name: "mint";
+          type: "publicKey";
         },
         {
-          name: "receiptManager";
-          isMut: false;
-          isSigner: false;
+          name: "expiresAt";
+          type: {
+            option: "i64";
+          };
         },
         {
-          name: "authority";
-          isMut: true;
-          isSigner: true;
+          name: "maxStakeSeconds";
+          type: {
+            option: "u128";
+          };
         },
       ];
-      args: [];
     },
     {
-      name: "claimRewardReceipt";
+      name: "deauthorizeMint";
       accounts: [
         {
-          name: "rewardReceipt";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "receiptManager";
+          name: "stakeAuthorizationRecord";
           isMut: true;
           isSigner: false;
         },
@@ -1038,12 +1369,7 @@ export type SolanaNftProgramsRewardsCenter = {
           isSigner: false;
         },
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
-        },
-        {
-          name: "claimer";
+          name: "authority";
           isMut: true;
           isSigner: true;
         },
@@ -1051,46 +1377,41 @@ export type SolanaNftProgramsRewardsCenter = {
       args: [];
     },
     {
-      name: "setRewardReceiptAllowed";
+      name: "syncStakeEntryAuthorization";
       accounts: [
         {
-          name: "receiptManager";
+          name: "stakePool";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "rewardReceipt";
+          name: "stakeEntry";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: true;
-          isSigner: true;
-        },
-      ];
-      args: [
-        {
-          name: "allowed";
-          type: "bool";
+          name: "stakeAuthorizationRecord";
+          isMut: false;
+          isSigner: false;
         },
       ];
+      args: [];
     },
     {
-      name: "initRewardDistributor";
+      name: "denyMint";
       accounts: [
         {
-          name: "rewardDistributor";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "stakePool";
-          isMut: false;
+          name: "stakeDenyRecord";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardMint";
+          name: "stakeEntry";
           isMut: true;
           isSigner: false;
         },
@@ -1104,11 +1425,6 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        },
         {
           name: "systemProgram";
           isMut: false;
@@ -1117,92 +1433,106 @@ export type SolanaNftProgramsRewardsCenter = {
       ];
       args: [
         {
-          name: "ix";
-          type: {
-            defined: "InitRewardDistributorIx";
-          };
+          name: "mint";
+          type: "publicKey";
         },
       ];
     },
     {
-      name: "updateRewardDistributor";
+      name: "undenyMint";
       accounts: [
         {
-          name: "rewardDistributor";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
+          name: "stakeDenyRecord";
+          isMut: true;
+          isSigner: false;
         },
-      ];
-      args: [
         {
-          name: "ix";
-          type: {
-            defined: "UpdateRewardDistributorIx";
-          };
+          name: "stakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
         },
       ];
+      args: [];
     },
     {
-      name: "closeRewardDistributor";
+      name: "syncStakeEntryDenied";
       accounts: [
         {
-          name: "rewardDistributor";
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "stakePool";
+          name: "stakeDenyRecord";
           isMut: false;
           isSigner: false;
         },
+      ];
+      args: [];
+    },
+    {
+      name: "initStakeBooster";
+      accounts: [
         {
-          name: "rewardMint";
+          name: "stakeBooster";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardDistributorTokenAccount";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authorityTokenAccount";
+          name: "authority";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "signer";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "tokenProgram";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitStakeBoosterIx";
+          };
+        },
+      ];
     },
     {
-      name: "reclaimFunds";
+      name: "updateStakeBooster";
       accounts: [
         {
-          name: "rewardDistributor";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "rewardDistributorTokenAccount";
+          name: "stakeBooster";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authorityTokenAccount";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
@@ -1211,60 +1541,59 @@ export type SolanaNftProgramsRewardsCenter = {
           isMut: true;
           isSigner: true;
         },
-        {
-          name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        },
       ];
       args: [
         {
-          name: "amount";
-          type: "u64";
+          name: "ix";
+          type: {
+            defined: "UpdateStakeBoosterIx";
+          };
         },
       ];
     },
     {
-      name: "initRewardEntry";
+      name: "boostStakeEntry";
       accounts: [
         {
-          name: "rewardEntry";
+          name: "stakeBooster";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "stakeEntry";
-          isMut: false;
-          isSigner: false;
-        },
-        {
-          name: "rewardDistributor";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "payer";
+          name: "stakeEntry";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "stakeMint";
           isMut: false;
           isSigner: false;
         },
       ];
-      args: [];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "BoostStakeEntryIx";
+          };
+        },
+      ];
     },
     {
-      name: "closeRewardEntry";
+      name: "closeStakeBooster";
       accounts: [
         {
-          name: "rewardDistributor";
-          isMut: false;
+          name: "stakeBooster";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardEntry";
+          name: "stakePool";
           isMut: true;
           isSigner: false;
         },
@@ -1277,81 +1606,100 @@ export type SolanaNftProgramsRewardsCenter = {
       args: [];
     },
     {
-      name: "updateRewardEntry";
+      name: "initReceiptManager";
       accounts: [
         {
-          name: "rewardEntry";
+          name: "receiptManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "rewardDistributor";
+          name: "stakePool";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: false;
+          name: "payer";
+          isMut: true;
           isSigner: true;
         },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
       ];
       args: [
         {
           name: "ix";
           type: {
-            defined: "UpdateRewardEntryIx";
+            defined: "InitReceiptManagerIx";
           };
         },
       ];
     },
     {
-      name: "claimRewards";
+      name: "updateReceiptManager";
       accounts: [
         {
-          name: "rewardEntry";
-          isMut: true;
-          isSigner: false;
-        },
-        {
-          name: "rewardDistributor";
+          name: "receiptManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "stakeEntry";
+          name: "authority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
+      ];
+      args: [
         {
-          name: "stakePool";
-          isMut: false;
-          isSigner: false;
+          name: "ix";
+          type: {
+            defined: "UpdateReceiptManagerIx";
+          };
         },
+      ];
+    },
+    {
+      name: "closeReceiptManager";
+      accounts: [
         {
-          name: "rewardMint";
+          name: "receiptManager";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "userRewardMintTokenAccount";
+          name: "authority";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
+      ];
+      args: [];
+    },
+    {
+      name: "initRewardReceipt";
+      accounts: [
         {
-          name: "rewardDistributorTokenAccount";
+          name: "rewardReceipt";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "user";
-          isMut: true;
-          isSigner: true;
+          name: "receiptManager";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "stakeEntry";
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -1361,71 +1709,77 @@ export type SolanaNftProgramsRewardsCenter = {
       args: [];
     },
     {
-      name: "initPaymentInfo";
+      name: "closeRewardReceipt";
       accounts: [
         {
-          name: "paymentInfo";
+          name: "rewardReceipt";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "payer";
-          isMut: true;
-          isSigner: true;
+          name: "receiptManager";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "stakeEntry";
           isMut: false;
           isSigner: false;
         },
-      ];
-      args: [
         {
-          name: "ix";
-          type: {
-            defined: "InitPaymentInfoIx";
-          };
+          name: "rentPayer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
         },
       ];
+      args: [];
     },
     {
-      name: "updatePaymentInfo";
+      name: "claimRewardReceipt";
       accounts: [
         {
-          name: "paymentInfo";
+          name: "rewardReceipt";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
+          name: "receiptManager";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer";
+          name: "stakeEntry";
           isMut: true;
-          isSigner: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
-      ];
-      args: [
         {
-          name: "ix";
-          type: {
-            defined: "UpdatePaymentInfoIx";
-          };
+          name: "claimer";
+          isMut: true;
+          isSigner: true;
         },
       ];
+      args: [];
     },
     {
-      name: "closePaymentInfo";
+      name: "setRewardReceiptAllowed";
       accounts: [
         {
-          name: "paymentInfo";
+          name: "receiptManager";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardReceipt";
           isMut: true;
           isSigner: false;
         },
@@ -1435,266 +1789,1558 @@ export type SolanaNftProgramsRewardsCenter = {
           isSigner: true;
         },
       ];
-      args: [];
-    },
-  ];
-  accounts: [
-    {
-      name: "stakeAuthorizationRecord";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "pool";
-            type: "publicKey";
-          },
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-        ];
-      };
+      args: [
+        {
+          name: "allowed";
+          type: "bool";
+        },
+      ];
     },
     {
-      name: "paymentInfo";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "identifier";
-            type: "string";
-          },
-          {
-            name: "paymentAmount";
-            type: "u64";
-          },
-          {
-            name: "paymentMint";
-            type: "publicKey";
-          },
-          {
-            name: "paymentShares";
-            type: {
-              vec: {
-                defined: "PaymentShare";
-              };
-            };
-          },
-        ];
-      };
+      name: "initRewardDistributor";
+      accounts: [
+        {
+          name: "rewardDistributor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitRewardDistributorIx";
+          };
+        },
+      ];
     },
     {
-      name: "rewardEntry";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "stakeEntry";
-            type: "publicKey";
-          },
-          {
-            name: "rewardDistributor";
-            type: "publicKey";
-          },
-          {
-            name: "rewardSecondsReceived";
-            type: "u128";
-          },
-          {
-            name: "multiplier";
-            type: "u64";
-          },
-        ];
-      };
+      name: "updateRewardDistributor";
+      accounts: [
+        {
+          name: "rewardDistributor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "UpdateRewardDistributorIx";
+          };
+        },
+      ];
     },
     {
-      name: "rewardDistributor";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "stakePool";
-            type: "publicKey";
-          },
-          {
-            name: "kind";
-            type: "u8";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "identifier";
-            type: "u64";
-          },
-          {
-            name: "rewardMint";
-            type: "publicKey";
-          },
-          {
-            name: "rewardAmount";
-            type: "u64";
-          },
-          {
-            name: "rewardDurationSeconds";
-            type: "u128";
-          },
-          {
-            name: "rewardsIssued";
-            type: "u128";
-          },
-          {
-            name: "defaultMultiplier";
-            type: "u64";
-          },
-          {
-            name: "multiplierDecimals";
-            type: "u8";
-          },
-          {
-            name: "claimRewardsPaymentInfo";
-            type: "publicKey";
-          },
-          {
-            name: "maxRewardSecondsReceived";
-            type: {
-              option: "u128";
-            };
-          },
-        ];
-      };
+      name: "closeRewardDistributor";
+      accounts: [
+        {
+          name: "rewardDistributor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authorityTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "signer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
     },
     {
-      name: "receiptManager";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
+      name: "reclaimFunds";
+      accounts: [
+        {
+          name: "rewardDistributor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authorityTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+      ];
+    },
+    {
+      name: "initRewardBonusRule";
+      accounts: [
+        {
+          name: "rewardBonusRule";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributor";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitRewardBonusRuleIx";
+          };
+        },
+      ];
+    },
+    {
+      name: "closeRewardBonusRule";
+      accounts: [
+        {
+          name: "rewardDistributor";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardBonusRule";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initRewardEntry";
+      accounts: [
+        {
+          name: "rewardEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "closeRewardEntry";
+      accounts: [
+        {
+          name: "rewardDistributor";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rentPayer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "updateRewardEntry";
+      accounts: [
+        {
+          name: "rewardEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributor";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "UpdateRewardEntryIx";
+          };
+        },
+      ];
+    },
+    {
+      name: "syncRewardEntryMultiplier";
+      accounts: [
+        {
+          name: "rewardEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributor";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "SyncRewardEntryMultiplierIx";
+          };
+        },
+      ];
+    },
+    {
+      name: "claimRewards";
+      accounts: [
+        {
+          name: "rewardEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributor";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakeEntry";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "stakePool";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "rewardMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "userRewardMintTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "rewardDistributorTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+          isOptional: true;
+        },
+      ];
+      args: [];
+    },
+    {
+      name: "initPaymentInfo";
+      accounts: [
+        {
+          name: "paymentInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitPaymentInfoIx";
+          };
+        },
+      ];
+    },
+    {
+      name: "updatePaymentInfo";
+      accounts: [
+        {
+          name: "paymentInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "UpdatePaymentInfoIx";
+          };
+        },
+      ];
+    },
+    {
+      name: "closePaymentInfo";
+      accounts: [
+        {
+          name: "paymentInfo";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        },
+      ];
+      args: [];
+    },
+  ];
+  accounts: [
+    {
+      name: "stakeAuthorizationRecord";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "maxStakeSeconds";
+            type: {
+              option: "u128";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "stakeDenyRecord";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+        ];
+      };
+    },
+    {
+      name: "leaderboard";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "kind";
+            type: "u8";
+          },
+          {
+            name: "rewardDistributor";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "size";
+            type: "u8";
+          },
+          {
+            name: "entries";
+            type: {
+              vec: {
+                defined: "LeaderboardEntry";
+              };
+            };
+          },
+          {
+            name: "season";
+            type: "u32";
+          },
+        ];
+      };
+    },
+    {
+      name: "paymentInfo";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "string";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "paymentShares";
+            type: {
+              vec: {
+                defined: "PaymentShare";
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "rewardEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "stakeEntry";
+            type: "publicKey";
+          },
+          {
+            name: "rewardDistributor";
+            type: "publicKey";
+          },
+          {
+            name: "rewardSecondsReceived";
+            type: "u128";
+          },
+          {
+            name: "multiplier";
+            type: "u64";
+          },
+          {
+            name: "rentPayer";
+            type: "publicKey";
+          },
+          {
+            name: "season";
+            type: "u32";
+          },
+          {
+            name: "swappedInStakeSeconds";
+            type: "u128";
+          },
+        ];
+      };
+    },
+    {
+      name: "rewardDistributor";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "stakePool";
+            type: "publicKey";
+          },
+          {
+            name: "kind";
+            type: "u8";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "rewardMint";
+            type: "publicKey";
+          },
+          {
+            name: "rewardAmount";
+            type: "u64";
+          },
+          {
+            name: "rewardDurationSeconds";
+            type: "u128";
+          },
+          {
+            name: "rewardsIssued";
+            type: "u128";
+          },
+          {
+            name: "defaultMultiplier";
+            type: "u64";
+          },
+          {
+            name: "multiplierDecimals";
+            type: "u8";
+          },
+          {
+            name: "claimRewardsPaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "maxRewardSecondsReceived";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "multiplierRoot";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
+          },
+          {
+            name: "rewardsFunded";
+            type: "u128";
+          },
+        ];
+      };
+    },
+    {
+      name: "rewardBonusRule";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "rewardDistributor";
+            type: "publicKey";
+          },
+          {
+            name: "collection";
+            type: "publicKey";
+          },
+          {
+            name: "multiplier";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "receiptManager";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "stakePool";
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "requiredStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "stakeSecondsToUse";
+            type: "u128";
+          },
+          {
+            name: "claimedReceiptsCounter";
+            type: "u128";
+          },
+          {
+            name: "requiresAuthorization";
+            type: "bool";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "paymentShares";
+            type: {
+              vec: {
+                defined: "PaymentShare";
+              };
+            };
+          },
+          {
+            name: "claimActionPaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "maxClaimedReceipts";
+            type: {
+              option: "u128";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "rewardReceipt";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "stakeEntry";
+            type: "publicKey";
+          },
+          {
+            name: "receiptManager";
+            type: "publicKey";
+          },
+          {
+            name: "target";
+            type: "publicKey";
+          },
+          {
+            name: "allowed";
+            type: "bool";
+          },
+          {
+            name: "rentPayer";
+            type: "publicKey";
+          },
+        ];
+      };
+    },
+    {
+      name: "stakeBooster";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "stakePool";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
+            type: "publicKey";
+          },
+          {
+            name: "paymentShares";
+            type: {
+              vec: {
+                defined: "PaymentShare";
+              };
+            };
+          },
+          {
+            name: "boostSeconds";
+            type: "u128";
+          },
+          {
+            name: "startTimeSeconds";
+            type: "i64";
+          },
+          {
+            name: "boostActionPaymentInfo";
+            type: "publicKey";
+          },
+        ];
+      };
+    },
+    {
+      name: "userEscrow";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "delegations";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "userStakeRegistry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "stakeEntries";
+            type: {
+              vec: {
+                defined: "UserStakeRegistryEntry";
+              };
+            };
+          },
+          {
+            name: "totalStaked";
+            type: "u64";
+          },
+          {
+            name: "totalStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "permitNonce";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "stakeEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "kind";
+            type: "u8";
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "stakeMint";
+            type: "publicKey";
+          },
+          {
+            name: "lastStaker";
+            type: "publicKey";
+          },
+          {
+            name: "lastStakedAt";
+            type: "i64";
+          },
+          {
+            name: "lastUpdatedAt";
+            type: "i64";
+          },
+          {
+            name: "totalStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "usedStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "cooldownStartSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "multiplierStakeSeconds";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "multiplierBasisPoints";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "denied";
+            type: "bool";
+          },
+          {
+            name: "stakeAuthorizationRecord";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "authorizationExpiresAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "authorizationMaxStakeSeconds";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "authorizationStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "rentPayer";
+            type: "publicKey";
+          },
+          {
+            name: "unlockAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "lockMultiplierBasisPoints";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "loyaltyRamp";
+            type: {
+              option: {
+                defined: "LoyaltyRamp";
+              };
+            };
+          },
+          {
+            name: "timedMultipliers";
+            type: {
+              array: [
+                {
+                  option: {
+                    defined: "TimedMultiplier";
+                  };
+                },
+                4,
+              ];
+            };
+          },
+          {
+            name: "stakeSet";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "stakeSetMultiplierBasisPoints";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "team";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "teamStakeSecondsCheckpoint";
+            type: "u128";
+          },
+          {
+            name: "teamPendingStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "season";
+            type: "u32";
+          },
+          {
+            name: "previousSeasonStakeSeconds";
+            type: "u128";
+          },
+          {
+            name: "unstakedAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "warmupEndsAt";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "swappedInStakeSeconds";
+            type: "u128";
+          },
+        ];
+      };
+    },
+    {
+      name: "stakePool";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "totalStaked";
+            type: "u32";
+          },
+          {
+            name: "resetOnUnstake";
+            type: "bool";
+          },
+          {
+            name: "cooldownSeconds";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "minStakeSeconds";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "endDate";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "stakePaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "unstakePaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "requiresAuthorization";
+            type: "bool";
+          },
+          {
+            name: "allowedCreators";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "allowedCollections";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "identifier";
+            type: "string";
+          },
+          {
+            name: "passMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "feeExemptMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "feeExemptBoostAndClaim";
+            type: "bool";
+          },
+          {
+            name: "allowedMintsRoot";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
+          },
+          {
+            name: "traitRulesRoot";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
+          },
+          {
+            name: "traitRules";
+            type: {
+              vec: {
+                defined: "TraitRule";
+              };
+            };
+          },
+          {
+            name: "deniedMints";
+            type: "u32";
+          },
+          {
+            name: "permitSigner";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "maxStaked";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "maxStakedPerWallet";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "lockTiers";
+            type: {
+              vec: {
+                defined: "LockTier";
+              };
+            };
+          },
+          {
+            name: "loyaltyRamp";
+            type: {
+              option: {
+                defined: "LoyaltyRamp";
+              };
+            };
+          },
+          {
+            name: "boostWindows";
+            type: {
+              vec: {
+                defined: "BoostWindow";
+              };
+            };
+          },
+          {
+            name: "stakeSetRules";
+            type: {
+              vec: {
+                defined: "StakeSetRule";
+              };
+            };
+          },
+          {
+            name: "season";
+            type: "u32";
+          },
+          {
+            name: "seasonStartedAt";
+            type: "i64";
+          },
+          {
+            name: "stakeSecondsDecay";
+            type: {
+              option: {
+                defined: "StakeSecondsDecay";
+              };
+            };
+          },
+          {
+            name: "warmupSeconds";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "accrueDuringCooldown";
+            type: "bool";
+          },
+          {
+            name: "swapStakeBasisPoints";
+            type: {
+              option: "u64";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "stakeSet";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "multiplierBasisPoints";
+            type: "u64";
+          },
+          {
+            name: "stakeEntries";
+            type: {
+              vec: "publicKey";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "team";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
             type: "u8";
           },
           {
-            name: "stakePool";
+            name: "pool";
+            type: "publicKey";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "memberCount";
+            type: "u32";
+          },
+          {
+            name: "totalStakeSeconds";
+            type: "u128";
+          },
+        ];
+      };
+    },
+  ];
+  types: [
+    {
+      name: "StakePermit";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pool";
             type: "publicKey";
           },
           {
-            name: "authority";
+            name: "mint";
             type: "publicKey";
           },
           {
-            name: "requiredStakeSeconds";
-            type: "u128";
+            name: "user";
+            type: "publicKey";
           },
           {
-            name: "stakeSecondsToUse";
-            type: "u128";
+            name: "expiresAt";
+            type: "i64";
           },
           {
-            name: "claimedReceiptsCounter";
-            type: "u128";
+            name: "nonce";
+            type: "u64";
           },
+        ];
+      };
+    },
+    {
+      name: "Trait";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "requiresAuthorization";
-            type: "bool";
+            name: "traitType";
+            type: "string";
           },
           {
-            name: "paymentAmount";
-            type: "u64";
+            name: "value";
+            type: "string";
           },
+        ];
+      };
+    },
+    {
+      name: "TraitRule";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "paymentMint";
-            type: "publicKey";
+            name: "matchAll";
+            type: "bool";
           },
           {
-            name: "paymentShares";
+            name: "traits";
             type: {
               vec: {
-                defined: "PaymentShare";
+                defined: "Trait";
               };
             };
           },
+        ];
+      };
+    },
+    {
+      name: "TraitProof";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "claimActionPaymentInfo";
-            type: "publicKey";
-          },
-          {
-            name: "name";
-            type: "string";
+            name: "traits";
+            type: {
+              vec: {
+                defined: "Trait";
+              };
+            };
           },
           {
-            name: "maxClaimedReceipts";
+            name: "proof";
             type: {
-              option: "u128";
+              vec: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
             };
           },
         ];
       };
     },
     {
-      name: "rewardReceipt";
+      name: "InitLeaderboardIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "bump";
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "kind";
             type: "u8";
           },
           {
-            name: "stakeEntry";
-            type: "publicKey";
+            name: "rewardDistributor";
+            type: {
+              option: "publicKey";
+            };
           },
           {
-            name: "receiptManager";
+            name: "size";
+            type: "u8";
+          },
+        ];
+      };
+    },
+    {
+      name: "LeaderboardEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "stakeEntry";
             type: "publicKey";
           },
           {
-            name: "target";
+            name: "user";
             type: "publicKey";
           },
           {
-            name: "allowed";
-            type: "bool";
+            name: "value";
+            type: "u128";
           },
         ];
       };
     },
     {
-      name: "stakeBooster";
+      name: "InitPaymentInfoIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "stakePool";
+            name: "authority";
             type: "publicKey";
           },
           {
             name: "identifier";
-            type: "u64";
+            type: "string";
           },
           {
             name: "paymentAmount";
@@ -1712,177 +3358,264 @@ export type SolanaNftProgramsRewardsCenter = {
               };
             };
           },
+        ];
+      };
+    },
+    {
+      name: "PaymentShare";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "boostSeconds";
-            type: "u128";
+            name: "address";
+            type: "publicKey";
           },
           {
-            name: "startTimeSeconds";
-            type: "i64";
+            name: "basisPoints";
+            type: "u16";
           },
+        ];
+      };
+    },
+    {
+      name: "UpdatePaymentInfoIx";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "boostActionPaymentInfo";
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
+          {
+            name: "paymentMint";
             type: "publicKey";
           },
+          {
+            name: "paymentShares";
+            type: {
+              vec: {
+                defined: "PaymentShare";
+              };
+            };
+          },
         ];
       };
     },
     {
-      name: "userEscrow";
+      name: "InitRewardBonusRuleIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "bump";
-            type: "u8";
+            name: "collection";
+            type: "publicKey";
           },
           {
-            name: "user";
-            type: "publicKey";
+            name: "multiplier";
+            type: "u64";
           },
         ];
       };
     },
     {
-      name: "stakeEntry";
+      name: "InitRewardDistributorIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "bump";
-            type: "u8";
+            name: "rewardAmount";
+            type: "u64";
           },
           {
-            name: "kind";
-            type: "u8";
+            name: "rewardDurationSeconds";
+            type: "u128";
           },
           {
-            name: "pool";
-            type: "publicKey";
+            name: "identifier";
+            type: "u64";
           },
           {
-            name: "amount";
-            type: "u64";
+            name: "supply";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "stakeMint";
-            type: "publicKey";
+            name: "defaultMultiplier";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "lastStaker";
+            name: "multiplierDecimals";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "maxRewardSecondsReceived";
+            type: {
+              option: "u128";
+            };
+          },
+          {
+            name: "claimRewardsPaymentInfo";
             type: "publicKey";
           },
           {
-            name: "lastStakedAt";
-            type: "i64";
+            name: "multiplierRoot";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
           },
+        ];
+      };
+    },
+    {
+      name: "UpdateRewardDistributorIx";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "lastUpdatedAt";
-            type: "i64";
+            name: "defaultMultiplier";
+            type: "u64";
           },
           {
-            name: "totalStakeSeconds";
-            type: "u128";
+            name: "multiplierDecimals";
+            type: "u8";
           },
           {
-            name: "usedStakeSeconds";
+            name: "rewardAmount";
+            type: "u64";
+          },
+          {
+            name: "rewardDurationSeconds";
             type: "u128";
           },
           {
-            name: "cooldownStartSeconds";
+            name: "maxRewardSecondsReceived";
             type: {
-              option: "i64";
+              option: "u128";
             };
           },
           {
-            name: "multiplierStakeSeconds";
+            name: "claimRewardsPaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "multiplierRoot";
             type: {
-              option: "u128";
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
             };
           },
+        ];
+      };
+    },
+    {
+      name: "SyncRewardEntryMultiplierIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "multiplier";
+            type: "u64";
+          },
           {
-            name: "multiplierBasisPoints";
+            name: "proof";
             type: {
-              option: "u64";
+              vec: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
             };
           },
         ];
       };
     },
     {
-      name: "stakePool";
+      name: "UpdateRewardEntryIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "bump";
-            type: "u8";
+            name: "multiplier";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "InitReceiptManagerIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
           },
           {
             name: "authority";
             type: "publicKey";
           },
           {
-            name: "totalStaked";
-            type: "u32";
+            name: "requiredStakeSeconds";
+            type: "u128";
           },
           {
-            name: "resetOnUnstake";
-            type: "bool";
+            name: "stakeSecondsToUse";
+            type: "u128";
           },
           {
-            name: "cooldownSeconds";
-            type: {
-              option: "u32";
-            };
+            name: "paymentMint";
+            type: "publicKey";
           },
           {
-            name: "minStakeSeconds";
-            type: {
-              option: "u32";
-            };
+            name: "paymentAmount";
+            type: "u64";
           },
           {
-            name: "endDate";
+            name: "paymentShares";
             type: {
-              option: "i64";
+              vec: {
+                defined: "PaymentShare";
+              };
             };
           },
-          {
-            name: "stakePaymentInfo";
-            type: "publicKey";
-          },
-          {
-            name: "unstakePaymentInfo";
-            type: "publicKey";
-          },
           {
             name: "requiresAuthorization";
             type: "bool";
           },
           {
-            name: "allowedCreators";
-            type: {
-              vec: "publicKey";
-            };
+            name: "claimActionPaymentInfo";
+            type: "publicKey";
           },
           {
-            name: "allowedCollections";
+            name: "maxClaimedReceipts";
             type: {
-              vec: "publicKey";
+              option: "u128";
             };
           },
-          {
-            name: "identifier";
-            type: "string";
-          },
         ];
       };
     },
-  ];
-  types: [
     {
-      name: "InitPaymentInfoIx";
+      name: "UpdateReceiptManagerIx";
       type: {
         kind: "struct";
         fields: [
@@ -1891,17 +3624,21 @@ export type SolanaNftProgramsRewardsCenter = {
             type: "publicKey";
           },
           {
-            name: "identifier";
-            type: "string";
+            name: "requiredStakeSeconds";
+            type: "u128";
           },
           {
-            name: "paymentAmount";
-            type: "u64";
+            name: "stakeSecondsToUse";
+            type: "u128";
           },
           {
             name: "paymentMint";
             type: "publicKey";
           },
+          {
+            name: "paymentAmount";
+            type: "u64";
+          },
           {
             name: "paymentShares";
             type: {
@@ -1910,34 +3647,48 @@ export type SolanaNftProgramsRewardsCenter = {
               };
             };
           },
+          {
+            name: "requiresAuthorization";
+            type: "bool";
+          },
+          {
+            name: "claimActionPaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "maxClaimedReceipts";
+            type: {
+              option: "u128";
+            };
+          },
         ];
       };
     },
     {
-      name: "PaymentShare";
+      name: "BoostStakeEntryIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "address";
-            type: "publicKey";
-          },
-          {
-            name: "basisPoints";
-            type: "u16";
+            name: "secondsToBoost";
+            type: "u64";
           },
         ];
       };
     },
     {
-      name: "UpdatePaymentInfoIx";
+      name: "InitStakeBoosterIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "authority";
+            name: "stakePool";
             type: "publicKey";
           },
+          {
+            name: "identifier";
+            type: "u64";
+          },
           {
             name: "paymentAmount";
             type: "u64";
@@ -1954,301 +3705,345 @@ export type SolanaNftProgramsRewardsCenter = {
               };
             };
           },
+          {
+            name: "boostSeconds";
+            type: "u128";
+          },
+          {
+            name: "startTimeSeconds";
+            type: "i64";
+          },
+          {
+            name: "boostActionPaymentInfo";
+            type: "publicKey";
+          },
         ];
       };
     },
     {
-      name: "InitRewardDistributorIx";
+      name: "UpdateStakeBoosterIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "rewardAmount";
-            type: "u64";
-          },
-          {
-            name: "rewardDurationSeconds";
-            type: "u128";
-          },
-          {
-            name: "identifier";
+            name: "paymentAmount";
             type: "u64";
           },
           {
-            name: "supply";
-            type: {
-              option: "u64";
-            };
+            name: "paymentMint";
+            type: "publicKey";
           },
           {
-            name: "defaultMultiplier";
+            name: "paymentShares";
             type: {
-              option: "u64";
+              vec: {
+                defined: "PaymentShare";
+              };
             };
           },
           {
-            name: "multiplierDecimals";
-            type: {
-              option: "u8";
-            };
+            name: "boostSeconds";
+            type: "u128";
           },
           {
-            name: "maxRewardSecondsReceived";
-            type: {
-              option: "u128";
-            };
+            name: "startTimeSeconds";
+            type: "i64";
           },
           {
-            name: "claimRewardsPaymentInfo";
+            name: "boostActionPaymentInfo";
             type: "publicKey";
           },
         ];
       };
     },
     {
-      name: "UpdateRewardDistributorIx";
+      name: "UserStakeRegistryEntry";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "defaultMultiplier";
-            type: "u64";
-          },
-          {
-            name: "multiplierDecimals";
-            type: "u8";
-          },
-          {
-            name: "rewardAmount";
-            type: "u64";
+            name: "stakeEntry";
+            type: "publicKey";
           },
           {
-            name: "rewardDurationSeconds";
+            name: "syncedStakeSeconds";
             type: "u128";
           },
-          {
-            name: "maxRewardSecondsReceived";
-            type: {
-              option: "u128";
-            };
-          },
-          {
-            name: "claimRewardsPaymentInfo";
-            type: "publicKey";
-          },
         ];
       };
     },
     {
-      name: "UpdateRewardEntryIx";
+      name: "TimedMultiplier";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "multiplier";
+            name: "multiplierBasisPoints";
             type: "u64";
           },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
         ];
       };
     },
     {
-      name: "InitReceiptManagerIx";
+      name: "InitPoolIx";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "name";
-            type: "string";
+            name: "allowedCollections";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "allowedCreators";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "requiresAuthorization";
+            type: "bool";
           },
           {
             name: "authority";
             type: "publicKey";
           },
           {
-            name: "requiredStakeSeconds";
-            type: "u128";
+            name: "resetOnUnstake";
+            type: "bool";
           },
           {
-            name: "stakeSecondsToUse";
-            type: "u128";
+            name: "cooldownSeconds";
+            type: {
+              option: "u32";
+            };
           },
           {
-            name: "paymentMint";
+            name: "minStakeSeconds";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "endDate";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "stakePaymentInfo";
             type: "publicKey";
           },
           {
-            name: "paymentAmount";
-            type: "u64";
+            name: "unstakePaymentInfo";
+            type: "publicKey";
+          },
+          {
+            name: "identifier";
+            type: "string";
+          },
+          {
+            name: "passMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "feeExemptMint";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "feeExemptBoostAndClaim";
+            type: "bool";
           },
           {
-            name: "paymentShares";
+            name: "allowedMintsRoot";
             type: {
-              vec: {
-                defined: "PaymentShare";
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
               };
             };
           },
           {
-            name: "requiresAuthorization";
-            type: "bool";
+            name: "traitRulesRoot";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
           },
           {
-            name: "claimActionPaymentInfo";
-            type: "publicKey";
+            name: "traitRules";
+            type: {
+              vec: {
+                defined: "TraitRule";
+              };
+            };
           },
           {
-            name: "maxClaimedReceipts";
+            name: "permitSigner";
             type: {
-              option: "u128";
+              option: "publicKey";
             };
           },
-        ];
-      };
-    },
-    {
-      name: "UpdateReceiptManagerIx";
-      type: {
-        kind: "struct";
-        fields: [
           {
-            name: "authority";
-            type: "publicKey";
+            name: "maxStaked";
+            type: {
+              option: "u32";
+            };
           },
           {
-            name: "requiredStakeSeconds";
-            type: "u128";
+            name: "maxStakedPerWallet";
+            type: {
+              option: "u32";
+            };
           },
           {
-            name: "stakeSecondsToUse";
-            type: "u128";
+            name: "lockTiers";
+            type: {
+              vec: {
+                defined: "LockTier";
+              };
+            };
           },
           {
-            name: "paymentMint";
-            type: "publicKey";
+            name: "loyaltyRamp";
+            type: {
+              option: {
+                defined: "LoyaltyRamp";
+              };
+            };
           },
           {
-            name: "paymentAmount";
-            type: "u64";
+            name: "boostWindows";
+            type: {
+              vec: {
+                defined: "BoostWindow";
+              };
+            };
           },
           {
-            name: "paymentShares";
+            name: "stakeSetRules";
             type: {
               vec: {
-                defined: "PaymentShare";
+                defined: "StakeSetRule";
               };
             };
           },
           {
-            name: "requiresAuthorization";
-            type: "bool";
+            name: "stakeSecondsDecay";
+            type: {
+              option: {
+                defined: "StakeSecondsDecay";
+              };
+            };
           },
           {
-            name: "claimActionPaymentInfo";
-            type: "publicKey";
+            name: "warmupSeconds";
+            type: {
+              option: "u32";
+            };
           },
           {
-            name: "maxClaimedReceipts";
+            name: "accrueDuringCooldown";
+            type: "bool";
+          },
+          {
+            name: "swapStakeBasisPoints";
             type: {
-              option: "u128";
+              option: "u64";
             };
           },
         ];
       };
     },
     {
-      name: "BoostStakeEntryIx";
+      name: "LockTier";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "secondsToBoost";
+            name: "lockSeconds";
+            type: "u32";
+          },
+          {
+            name: "multiplierBasisPoints";
             type: "u64";
           },
         ];
       };
     },
     {
-      name: "InitStakeBoosterIx";
+      name: "LoyaltyRamp";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "stakePool";
-            type: "publicKey";
+            name: "intervalSeconds";
+            type: "u32";
           },
           {
-            name: "identifier";
+            name: "incrementBasisPoints";
             type: "u64";
           },
           {
-            name: "paymentAmount";
+            name: "maxBonusBasisPoints";
             type: "u64";
           },
-          {
-            name: "paymentMint";
-            type: "publicKey";
-          },
-          {
-            name: "paymentShares";
-            type: {
-              vec: {
-                defined: "PaymentShare";
-              };
-            };
-          },
-          {
-            name: "boostSeconds";
-            type: "u128";
-          },
-          {
-            name: "startTimeSeconds";
-            type: "i64";
-          },
-          {
-            name: "boostActionPaymentInfo";
-            type: "publicKey";
-          },
         ];
       };
     },
     {
-      name: "UpdateStakeBoosterIx";
+      name: "StakeSecondsDecay";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "paymentAmount";
-            type: "u64";
-          },
-          {
-            name: "paymentMint";
-            type: "publicKey";
+            name: "kind";
+            type: "u8";
           },
           {
-            name: "paymentShares";
-            type: {
-              vec: {
-                defined: "PaymentShare";
-              };
-            };
+            name: "periodSeconds";
+            type: "u32";
           },
+        ];
+      };
+    },
+    {
+      name: "BoostWindow";
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "boostSeconds";
-            type: "u128";
+            name: "start";
+            type: "i64";
           },
           {
-            name: "startTimeSeconds";
+            name: "end";
             type: "i64";
           },
           {
-            name: "boostActionPaymentInfo";
-            type: "publicKey";
+            name: "multiplierBasisPoints";
+            type: "u64";
           },
         ];
       };
     },
     {
-      name: "InitPoolIx";
+      name: "UpdatePoolIx";
       type: {
         kind: "struct";
         fields: [
@@ -2303,66 +4098,192 @@ export type SolanaNftProgramsRewardsCenter = {
             type: "publicKey";
           },
           {
-            name: "identifier";
-            type: "string";
+            name: "passMint";
+            type: {
+              option: "publicKey";
+            };
           },
-        ];
-      };
-    },
-    {
-      name: "UpdatePoolIx";
-      type: {
-        kind: "struct";
-        fields: [
           {
-            name: "allowedCollections";
+            name: "feeExemptMint";
             type: {
-              vec: "publicKey";
+              option: "publicKey";
             };
           },
           {
-            name: "allowedCreators";
+            name: "feeExemptBoostAndClaim";
+            type: "bool";
+          },
+          {
+            name: "allowedMintsRoot";
             type: {
-              vec: "publicKey";
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
             };
           },
           {
-            name: "requiresAuthorization";
-            type: "bool";
+            name: "traitRulesRoot";
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ];
+              };
+            };
           },
           {
-            name: "authority";
-            type: "publicKey";
+            name: "traitRules";
+            type: {
+              vec: {
+                defined: "TraitRule";
+              };
+            };
           },
           {
-            name: "resetOnUnstake";
-            type: "bool";
+            name: "permitSigner";
+            type: {
+              option: "publicKey";
+            };
           },
           {
-            name: "cooldownSeconds";
+            name: "maxStaked";
             type: {
               option: "u32";
             };
           },
           {
-            name: "minStakeSeconds";
+            name: "maxStakedPerWallet";
+            type: {
+              option: "u32";
+            };
+          },
+          {
+            name: "lockTiers";
+            type: {
+              vec: {
+                defined: "LockTier";
+              };
+            };
+          },
+          {
+            name: "loyaltyRamp";
+            type: {
+              option: {
+                defined: "LoyaltyRamp";
+              };
+            };
+          },
+          {
+            name: "boostWindows";
+            type: {
+              vec: {
+                defined: "BoostWindow";
+              };
+            };
+          },
+          {
+            name: "stakeSetRules";
+            type: {
+              vec: {
+                defined: "StakeSetRule";
+              };
+            };
+          },
+          {
+            name: "stakeSecondsDecay";
+            type: {
+              option: {
+                defined: "StakeSecondsDecay";
+              };
+            };
+          },
+          {
+            name: "warmupSeconds";
             type: {
               option: "u32";
             };
           },
           {
-            name: "endDate";
-            type: {
-              option: "i64";
-            };
+            name: "accrueDuringCooldown";
+            type: "bool";
+          },
+          {
+            name: "swapStakeBasisPoints";
+            type: {
+              option: "u64";
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "FormStakeSetIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "identifier";
+            type: "u64";
+          },
+          {
+            name: "rule";
+            type: "u8";
+          },
+          {
+            name: "traitProofs";
+            type: {
+              vec: {
+                defined: "TraitProof";
+              };
+            };
+          },
+        ];
+      };
+    },
+    {
+      name: "StakeSetRule";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collections";
+            type: {
+              vec: "publicKey";
+            };
+          },
+          {
+            name: "traitMatch";
+            type: {
+              option: {
+                defined: "Trait";
+              };
+            };
+          },
+          {
+            name: "size";
+            type: "u8";
           },
           {
-            name: "stakePaymentInfo";
-            type: "publicKey";
+            name: "multiplierBasisPoints";
+            type: "u64";
+          },
+        ];
+      };
+    },
+    {
+      name: "LeaderboardKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "TotalStakeSeconds";
           },
           {
-            name: "unstakePaymentInfo";
-            type: "publicKey";
+            name: "RewardSecondsReceived";
           },
         ];
       };
@@ -2390,6 +4311,20 @@ export type SolanaNftProgramsRewardsCenter = {
         ];
       };
     },
+    {
+      name: "StakeSecondsDecayKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "HalfLife";
+          },
+          {
+            name: "Linear";
+          },
+        ];
+      };
+    },
   ];
   errors: [
     {
@@ -2412,6 +4347,11 @@ export type SolanaNftProgramsRewardsCenter = {
       name: "InvalidEscrow";
       msg: "Mismatched user and escrow";
     },
+    {
+      code: 6004;
+      name: "InvalidRentPayer";
+      msg: "Invalid rent payer";
+    },
     {
       code: 6010;
       name: "InvalidUserStakeMintTokenAccount";
@@ -2437,6 +4377,16 @@ export type SolanaNftProgramsRewardsCenter = {
       name: "CannotClosePoolWithStakedEntries";
       msg: "Cannot close staked entry";
     },
+    {
+      code: 6015;
+      name: "CannotCloseEscrowWithDelegations";
+      msg: "Cannot close escrow with outstanding delegations";
+    },
+    {
+      code: 6016;
+      name: "StakeEntryNotClosed";
+      msg: "Stake entry must be closed first";
+    },
     {
       code: 6020;
       name: "InvalidMintMetadata";
@@ -2457,6 +4407,11 @@ export type SolanaNftProgramsRewardsCenter = {
       name: "InvalidMintMetadataOwner";
       msg: "Mint metadata is owned by the incorrect program";
     },
+    {
+      code: 6024;
+      name: "StakeAuthorizationExpired";
+      msg: "Stake authorization has expired";
+    },
     {
       code: 6030;
       name: "InvalidPaymentMint";
@@ -2492,6 +4447,11 @@ export type SolanaNftProgramsRewardsCenter = {
       name: "CooldownSecondRemaining";
       msg: "Token still has some cooldown seconds remaining";
     },
+    {
+      code: 6041;
+      name: "StakeEntryNotInCooldown";
+      msg: "Stake entry is not in cooldown";
+    },
     {
       code: 6050;
       name: "StakePoolHasEnded";
@@ -2502,6 +4462,41 @@ export type SolanaNftProgramsRewardsCenter = {
       name: "MinStakeSecondsNotSatisfied";
       msg: "Minimum stake seconds not satisfied";
     },
+    {
+      code: 6052;
+      name: "MaxStakedReached";
+      msg: "Stake pool has reached its maximum staked";
+    },
+    {
+      code: 6053;
+      name: "MaxStakedPerWalletReached";
+      msg: "User has reached the maximum staked per wallet";
+    },
+    {
+      code: 6054;
+      name: "InvalidLockTier";
+      msg: "Invalid lock tier";
+    },
+    {
+      code: 6055;
+      name: "StakeEntryLocked";
+      msg: "Stake entry is still locked";
+    },
+    {
+      code: 6056;
+      name: "InvalidStakeSecondsDecay";
+      msg: "Invalid stake seconds decay";
+    },
+    {
+      code: 6057;
+      name: "InvalidLoyaltyRamp";
+      msg: "Invalid loyalty ramp";
+    },
+    {
+      code: 6058;
+      name: "InvalidBoostWindow";
+      msg: "Invalid boost window";
+    },
     {
       code: 6060;
       name: "CannotBoostUnstakedToken";
@@ -2588,112 +4583,750 @@ export type SolanaNftProgramsRewardsCenter = {
       msg: "Reward receipt already claimed";
     },
     {
-      code: 6090;
-      name: "InvalidTokenAccount";
-      msg: "Invalid token account";
+      code: 6090;
+      name: "InvalidTokenAccount";
+      msg: "Invalid token account";
+    },
+    {
+      code: 6091;
+      name: "InvalidRewardMint";
+      msg: "Invalid reward mint";
+    },
+    {
+      code: 6092;
+      name: "InvalidUserRewardMintTokenAccount";
+      msg: "Invalid user reward mint token account";
+    },
+    {
+      code: 6093;
+      name: "InvalidRewardDistributor";
+      msg: "Invalid reward distributor";
+    },
+    {
+      code: 6094;
+      name: "InvalidRewardDistributorAuthority";
+      msg: "Invalid reward distributor authority";
+    },
+    {
+      code: 6095;
+      name: "InvalidRewardDistributorKind";
+      msg: "Invalid reward distributor kind";
+    },
+    {
+      code: 6096;
+      name: "SupplyRequired";
+      msg: "Initial supply required for kind treasury";
+    },
+    {
+      code: 6097;
+      name: "InvalidPoolDistributor";
+      msg: "Invalid distributor for pool";
+    },
+    {
+      code: 6098;
+      name: "DistributorNotClosed";
+      msg: "Distributor is already open";
+    },
+    {
+      code: 6099;
+      name: "DistributorAlreadyClosed";
+      msg: "Distributor is already closed";
+    },
+    {
+      code: 6100;
+      name: "InvalidRewardEntry";
+      msg: "Invalid reward entry";
+    },
+    {
+      code: 6101;
+      name: "InvalidRewardDistributorTokenAccount";
+      msg: "Invalid reward distributor token account";
+    },
+    {
+      code: 6102;
+      name: "InvalidAuthorityTokenAccount";
+      msg: "Invalid authority token account";
+    },
+    {
+      code: 6103;
+      name: "MaxRewardSecondsClaimed";
+      msg: "Max reward seconds claimed";
+    },
+    {
+      code: 6104;
+      name: "InvalidRewardMultiplierProof";
+      msg: "Invalid reward multiplier proof";
+    },
+    {
+      code: 6105;
+      name: "InvalidRewardBonusRule";
+      msg: "Invalid reward bonus rule";
+    },
+    {
+      code: 6106;
+      name: "InvalidRewardBonusEvidence";
+      msg: "Invalid reward bonus evidence";
+    },
+    {
+      code: 6110;
+      name: "InvalidPassTokenAccount";
+      msg: "Invalid pass token account";
+    },
+    {
+      code: 6111;
+      name: "InvalidPassMintMetadata";
+      msg: "Invalid pass mint metadata";
+    },
+    {
+      code: 6120;
+      name: "InvalidTraitProof";
+      msg: "Invalid trait proof";
+    },
+    {
+      code: 6121;
+      name: "TraitRulesNotSatisfied";
+      msg: "Mint traits do not satisfy the pool trait rules";
+    },
+    {
+      code: 6130;
+      name: "MintDenied";
+      msg: "Mint is denied in this pool";
+    },
+    {
+      code: 6131;
+      name: "InvalidStakeDenyRecord";
+      msg: "Invalid stake deny record";
+    },
+    {
+      code: 6140;
+      name: "InvalidStakePermit";
+      msg: "Invalid stake permit";
+    },
+    {
+      code: 6141;
+      name: "StakePermitExpired";
+      msg: "Stake permit has expired";
+    },
+    {
+      code: 6142;
+      name: "StakePermitNonceUsed";
+      msg: "Stake permit nonce already used";
+    },
+    {
+      code: 6150;
+      name: "InvalidTimedMultiplierExpiry";
+      msg: "Timed multiplier must expire in the future";
+    },
+    {
+      code: 6151;
+      name: "TimedMultipliersFull";
+      msg: "No free timed multiplier slots on stake entry";
+    },
+    {
+      code: 6160;
+      name: "InvalidStakeSetRule";
+      msg: "Invalid stake set rule";
+    },
+    {
+      code: 6161;
+      name: "InvalidStakeSetMember";
+      msg: "Invalid stake set member";
+    },
+    {
+      code: 6162;
+      name: "InvalidStakeSet";
+      msg: "Invalid stake set";
+    },
+    {
+      code: 6170;
+      name: "InvalidTeam";
+      msg: "Invalid team";
+    },
+    {
+      code: 6171;
+      name: "StakeEntryAlreadyInTeam";
+      msg: "Stake entry is already in a team";
+    },
+    {
+      code: 6172;
+      name: "StakeEntryNotInTeam";
+      msg: "Stake entry is not in this team";
+    },
+    {
+      code: 6173;
+      name: "CannotCloseTeamWithMembers";
+      msg: "Cannot close team with members";
+    },
+    {
+      code: 6180;
+      name: "InvalidLeaderboard";
+      msg: "Invalid leaderboard";
+    },
+    {
+      code: 6181;
+      name: "InvalidLeaderboardKind";
+      msg: "Invalid leaderboard kind";
+    },
+    {
+      code: 6182;
+      name: "InvalidLeaderboardSize";
+      msg: "Invalid leaderboard size";
+    },
+    {
+      code: 6183;
+      name: "LeaderboardSizeTooLarge";
+      msg: "Leaderboard size exceeds the maximum account size";
+    },
+    {
+      code: 6184;
+      name: "LeaderboardSeasonMismatch";
+      msg: "Stake entry is not in the leaderboard's season";
+    },
+    {
+      code: 6190;
+      name: "StakeSwapDisabled";
+      msg: "Stake swaps are disabled for this pool";
+    },
+    {
+      code: 6191;
+      name: "InvalidStakeSwap";
+      msg: "Invalid stake swap";
+    },
+    {
+      code: 6192;
+      name: "InvalidStakeSwapRewardEntry";
+      msg: "Invalid stake swap reward entry";
+    },
+    {
+      code: 6193;
+      name: "StakeSwapSourceNotUnstaked";
+      msg: "Stake swap source entry must be unstaked later in the transaction";
+    },
+  ];
+};
+
+export const IDL: SolanaNftProgramsRewardsCenter = {
+  version: "1.0.0",
+  name: "solana_nft_programs_rewards_center",
+  instructions: [
+    {
+      name: "initPool",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitPoolIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "updatePool",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdatePoolIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateStakePool",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "advanceSeason",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6091;
-      name: "InvalidRewardMint";
-      msg: "Invalid reward mint";
+      name: "closeStakePool",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6092;
-      name: "InvalidUserRewardMintTokenAccount";
-      msg: "Invalid user reward mint token account";
+      name: "initEntry",
+      accounts: [
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeMintMetadata",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "user",
+          type: "publicKey",
+        },
+        {
+          name: "mintProof",
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        },
+        {
+          name: "traitProof",
+          type: {
+            option: {
+              defined: "TraitProof",
+            },
+          },
+        },
+      ],
     },
     {
-      code: 6093;
-      name: "InvalidRewardDistributor";
-      msg: "Invalid reward distributor";
+      name: "updateTotalStakeSeconds",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "updater",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6094;
-      name: "InvalidRewardDistributorAuthority";
-      msg: "Invalid reward distributor authority";
+      name: "resetStakeEntry",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6095;
-      name: "InvalidRewardDistributorKind";
-      msg: "Invalid reward distributor kind";
+      name: "setStakeEntryMultiplier",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "multiplierBasisPoints",
+          type: {
+            option: "u64",
+          },
+        },
+      ],
     },
     {
-      code: 6096;
-      name: "SupplyRequired";
-      msg: "Initial supply required for kind treasury";
+      name: "setStakeEntryMultiplierStakeSeconds",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "multiplierStakeSeconds",
+          type: {
+            option: "u128",
+          },
+        },
+      ],
     },
     {
-      code: 6097;
-      name: "InvalidPoolDistributor";
-      msg: "Invalid distributor for pool";
+      name: "incrementStakeEntryMultiplierStakeSeconds",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "multiplierStakeSeconds",
+          type: "u128",
+        },
+      ],
     },
     {
-      code: 6098;
-      name: "DistributorNotClosed";
-      msg: "Distributor is already open";
+      name: "decrementStakeEntryMultiplierStakeSeconds",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "multiplierStakeSeconds",
+          type: "u128",
+        },
+      ],
     },
     {
-      code: 6099;
-      name: "DistributorAlreadyClosed";
-      msg: "Distributor is already closed";
+      name: "addStakeEntryTimedMultiplier",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "multiplierBasisPoints",
+          type: "u64",
+        },
+        {
+          name: "expiresAt",
+          type: "i64",
+        },
+      ],
     },
     {
-      code: 6100;
-      name: "InvalidRewardEntry";
-      msg: "Invalid reward entry";
+      name: "resizeStakeEntry",
+      accounts: [
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6101;
-      name: "InvalidRewardDistributorTokenAccount";
-      msg: "Invalid reward distributor token account";
+      name: "closeStakeEntry",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rentPayer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6102;
-      name: "InvalidAuthorityTokenAccount";
-      msg: "Invalid authority token account";
+      name: "closeUserEscrow",
+      accounts: [
+        {
+          name: "userEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
     {
-      code: 6103;
-      name: "MaxRewardSecondsClaimed";
-      msg: "Max reward seconds claimed";
+      name: "cancelCooldown",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
     },
-  ];
-};
-
-export const IDL: SolanaNftProgramsRewardsCenter = {
-  version: "1.0.0",
-  name: "solana_nft_programs_rewards_center",
-  instructions: [
     {
-      name: "initPool",
+      name: "swapStake",
       accounts: [
         {
           name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "fromStakeEntry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "payer",
+          name: "toStakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "userStakeRegistry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
       ],
-      args: [
+      args: [],
+    },
+    {
+      name: "backfillUserStakeRegistry",
+      accounts: [
         {
-          name: "ix",
-          type: {
-            defined: "InitPoolIx",
-          },
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "userStakeRegistry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "updatePool",
+      name: "stakeEdition",
       accounts: [
         {
           name: "stakePool",
@@ -2701,15 +5334,55 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeMint",
           isMut: false,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "payer",
+          name: "stakeMintEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeMintMetadata",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
           isMut: true,
           isSigner: true,
         },
+        {
+          name: "userEscrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userStakeRegistry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "userStakeMintTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "systemProgram",
           isMut: false,
@@ -2718,15 +5391,38 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       ],
       args: [
         {
-          name: "ix",
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "mintProof",
           type: {
-            defined: "UpdatePoolIx",
+            vec: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        },
+        {
+          name: "traitProof",
+          type: {
+            option: {
+              defined: "TraitProof",
+            },
+          },
+        },
+        {
+          name: "lockTier",
+          type: {
+            option: "u8",
           },
         },
       ],
     },
     {
-      name: "closeStakePool",
+      name: "unstakeEdition",
       accounts: [
         {
           name: "stakePool",
@@ -2734,153 +5430,171 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "stakeMintEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "user",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "initEntry",
-      accounts: [
         {
-          name: "stakeEntry",
+          name: "userEscrow",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakePool",
+          name: "userStakeRegistry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeMint",
-          isMut: false,
+          name: "userStakeMintTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeMintMetadata",
+          name: "tokenMetadataProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
         },
         {
           name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "user",
-          type: "publicKey",
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
         },
       ],
+      args: [],
     },
     {
-      name: "updateTotalStakeSeconds",
+      name: "stakeCcs",
       accounts: [
         {
-          name: "stakeEntry",
+          name: "stakePool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "updater",
+          name: "stakeEntry",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "resetStakeEntry",
-      accounts: [
         {
-          name: "stakePool",
+          name: "stakeMint",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
-          isMut: true,
+          name: "stakeMintMetadata",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "stakeMintManager",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "setStakeEntryMultiplier",
-      accounts: [
         {
-          name: "stakePool",
+          name: "stakeMintManagerRuleset",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
+          name: "user",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "userEscrow",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "userStakeRegistry",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
-      ],
-      args: [
         {
-          name: "multiplierBasisPoints",
-          type: {
-            option: "u64",
-          },
+          name: "userStakeMintTokenAccount",
+          isMut: true,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "setStakeEntryMultiplierStakeSeconds",
-      accounts: [
         {
-          name: "stakePool",
+          name: "creatorStandardProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
-          isMut: true,
+          name: "tokenProgram",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
       args: [
         {
-          name: "multiplierStakeSeconds",
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "mintProof",
           type: {
-            option: "u128",
+            vec: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        },
+        {
+          name: "traitProof",
+          type: {
+            option: {
+              defined: "TraitProof",
+            },
+          },
+        },
+        {
+          name: "lockTier",
+          type: {
+            option: "u8",
           },
         },
       ],
     },
     {
-      name: "incrementStakeEntryMultiplierStakeSeconds",
+      name: "unstakeCcs",
       accounts: [
         {
           name: "stakePool",
-          isMut: false,
+          isMut: true,
           isSigner: false,
         },
         {
@@ -2889,88 +5603,61 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "multiplierStakeSeconds",
-          type: "u128",
-        },
-      ],
-    },
-    {
-      name: "decrementStakeEntryMultiplierStakeSeconds",
-      accounts: [
-        {
-          name: "stakePool",
+          name: "stakeMint",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
+          name: "stakeMintManager",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "user",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "multiplierStakeSeconds",
-          type: "u128",
+          name: "userEscrow",
+          isMut: true,
+          isSigner: false,
         },
-      ],
-    },
-    {
-      name: "resizeStakeEntry",
-      accounts: [
         {
-          name: "stakeEntry",
+          name: "userStakeRegistry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "payer",
+          name: "userStakeMintTokenAccount",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "creatorStandardProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "closeStakeEntry",
-      accounts: [
         {
-          name: "stakePool",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
-          isMut: true,
+          name: "systemProgram",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "leaderboard",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
+          isOptional: true,
         },
       ],
       args: [],
     },
     {
-      name: "stakeEdition",
+      name: "stakePnft",
       accounts: [
         {
           name: "stakePool",
@@ -2987,13 +5674,23 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "stakeMintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "stakeMintEdition",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeMintMetadata",
+          name: "stakeTokenRecordAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorizationRules",
           isMut: false,
           isSigner: false,
         },
@@ -3007,6 +5704,11 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "userStakeRegistry",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "userStakeMintTokenAccount",
           isMut: true,
@@ -3017,6 +5719,16 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "sysvarInstructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authorizationRulesProgram",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "tokenProgram",
           isMut: false,
@@ -3030,13 +5742,34 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       ],
       args: [
         {
-          name: "amount",
-          type: "u64",
+          name: "mintProof",
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        },
+        {
+          name: "traitProof",
+          type: {
+            option: {
+              defined: "TraitProof",
+            },
+          },
+        },
+        {
+          name: "lockTier",
+          type: {
+            option: "u8",
+          },
         },
       ],
     },
     {
-      name: "unstakeEdition",
+      name: "unstakePnft",
       accounts: [
         {
           name: "stakePool",
@@ -3053,11 +5786,26 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "stakeMintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "stakeMintEdition",
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "stakeTokenRecordAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorizationRules",
+          isMut: false,
+          isSigner: false,
+        },
         {
           name: "user",
           isMut: true,
@@ -3069,58 +5817,59 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "userStakeMintTokenAccount",
+          name: "userStakeRegistry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
+          name: "userStakeMintTokenAccount",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "tokenMetadataProgram",
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [],
-    },
-    {
-      name: "stakeCcs",
-      accounts: [
-        {
-          name: "stakePool",
-          isMut: true,
+        {
+          name: "sysvarInstructions",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
-          isMut: true,
+          name: "authorizationRulesProgram",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeMint",
+          name: "tokenProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeMintMetadata",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeMintManager",
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "formStakeSet",
+      accounts: [
+        {
+          name: "stakeSet",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeMintManagerRuleset",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
@@ -3130,24 +5879,42 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: true,
         },
         {
-          name: "userEscrow",
-          isMut: true,
+          name: "systemProgram",
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "userStakeMintTokenAccount",
+          name: "ix",
+          type: {
+            defined: "FormStakeSetIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "initTeam",
+      accounts: [
+        {
+          name: "team",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "creatorStandardProgram",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "authority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
         },
         {
           name: "systemProgram",
@@ -3157,150 +5924,193 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       ],
       args: [
         {
-          name: "amount",
+          name: "identifier",
           type: "u64",
         },
       ],
     },
     {
-      name: "unstakeCcs",
+      name: "joinTeam",
       accounts: [
         {
-          name: "stakePool",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "stakeEntry",
+          name: "team",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeMint",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeMintManager",
+          name: "stakeEntry",
           isMut: true,
           isSigner: false,
         },
         {
           name: "user",
-          isMut: true,
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "userEscrow",
-          isMut: true,
-          isSigner: false,
+          name: "authority",
+          isMut: false,
+          isSigner: true,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "leaveTeam",
+      accounts: [
         {
-          name: "userStakeMintTokenAccount",
+          name: "team",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "creatorStandardProgram",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "stakeEntry",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "user",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
       ],
       args: [],
     },
     {
-      name: "stakePnft",
+      name: "syncTeamStakeSeconds",
       accounts: [
         {
-          name: "stakePool",
+          name: "team",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeEntry",
-          isMut: true,
+          name: "stakePool",
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeMint",
-          isMut: false,
+          name: "stakeEntry",
+          isMut: true,
           isSigner: false,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "closeTeam",
+      accounts: [
         {
-          name: "stakeMintMetadata",
+          name: "team",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeMintEdition",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          isMut: true,
+          isSigner: true,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "initLeaderboard",
+      accounts: [
         {
-          name: "stakeTokenRecordAccount",
+          name: "leaderboard",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authorizationRules",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "user",
-          isMut: true,
+          name: "authority",
+          isMut: false,
           isSigner: true,
         },
         {
-          name: "userEscrow",
+          name: "payer",
           isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitLeaderboardIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateLeaderboard",
+      accounts: [
         {
-          name: "userStakeMintTokenAccount",
+          name: "leaderboard",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenMetadataProgram",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "sysvarInstructions",
+          name: "stakeEntry",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "authorizationRulesProgram",
+          name: "rewardEntry",
           isMut: false,
           isSigner: false,
+          isOptional: true,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "closeLeaderboard",
+      accounts: [
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "leaderboard",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
       ],
       args: [],
     },
     {
-      name: "unstakePnft",
+      name: "authorizeMint",
       accounts: [
         {
           name: "stakePool",
@@ -3308,72 +6118,86 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "stakeEntry",
+          name: "stakeAuthorizationRecord",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakeMint",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          isMut: true,
+          isSigner: true,
         },
         {
-          name: "stakeMintMetadata",
+          name: "payer",
           isMut: true,
-          isSigner: false,
+          isSigner: true,
         },
         {
-          name: "stakeMintEdition",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "stakeTokenRecordAccount",
-          isMut: true,
-          isSigner: false,
+          name: "mint",
+          type: "publicKey",
         },
         {
-          name: "authorizationRules",
-          isMut: false,
-          isSigner: false,
+          name: "expiresAt",
+          type: {
+            option: "i64",
+          },
         },
         {
-          name: "user",
-          isMut: true,
-          isSigner: true,
+          name: "maxStakeSeconds",
+          type: {
+            option: "u128",
+          },
         },
+      ],
+    },
+    {
+      name: "deauthorizeMint",
+      accounts: [
         {
-          name: "userEscrow",
+          name: "stakePool",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "userStakeMintTokenAccount",
+          name: "stakeAuthorizationRecord",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
+          name: "stakeEntry",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "sysvarInstructions",
-          isMut: false,
-          isSigner: false,
+          name: "authority",
+          isMut: true,
+          isSigner: true,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "syncStakeEntryAuthorization",
+      accounts: [
         {
-          name: "authorizationRulesProgram",
+          name: "stakePool",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "tokenProgram",
-          isMut: false,
+          name: "stakeEntry",
+          isMut: true,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "stakeAuthorizationRecord",
           isMut: false,
           isSigner: false,
         },
@@ -3381,7 +6205,7 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       args: [],
     },
     {
-      name: "authorizeMint",
+      name: "denyMint",
       accounts: [
         {
           name: "stakePool",
@@ -3389,7 +6213,12 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "stakeAuthorizationRecord",
+          name: "stakeDenyRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
           isMut: true,
           isSigner: false,
         },
@@ -3408,31 +6237,57 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: false,
           isSigner: false,
         },
-      ],
-      args: [
+      ],
+      args: [
+        {
+          name: "mint",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "undenyMint",
+      accounts: [
+        {
+          name: "stakePool",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeDenyRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
         {
-          name: "mint",
-          type: "publicKey",
+          name: "authority",
+          isMut: true,
+          isSigner: true,
         },
       ],
+      args: [],
     },
     {
-      name: "deauthorizeMint",
+      name: "syncStakeEntryDenied",
       accounts: [
         {
           name: "stakePool",
-          isMut: true,
+          isMut: false,
           isSigner: false,
         },
         {
-          name: "stakeAuthorizationRecord",
+          name: "stakeEntry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
+          name: "stakeDenyRecord",
+          isMut: false,
+          isSigner: false,
         },
       ],
       args: [],
@@ -3673,6 +6528,16 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "stakeEntry",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rentPayer",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "authority",
           isMut: true,
@@ -3713,46 +6578,289 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       args: [],
     },
     {
-      name: "setRewardReceiptAllowed",
+      name: "setRewardReceiptAllowed",
+      accounts: [
+        {
+          name: "receiptManager",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardReceipt",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "allowed",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "initRewardDistributor",
+      accounts: [
+        {
+          name: "rewardDistributor",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitRewardDistributorIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateRewardDistributor",
+      accounts: [
+        {
+          name: "rewardDistributor",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateRewardDistributorIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "closeRewardDistributor",
+      accounts: [
+        {
+          name: "rewardDistributor",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakePool",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardDistributorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "signer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "reclaimFunds",
+      accounts: [
+        {
+          name: "rewardDistributor",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardDistributorTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "initRewardBonusRule",
+      accounts: [
+        {
+          name: "rewardBonusRule",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "rewardDistributor",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitRewardBonusRuleIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "closeRewardBonusRule",
+      accounts: [
+        {
+          name: "rewardDistributor",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardBonusRule",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "initRewardEntry",
       accounts: [
         {
-          name: "receiptManager",
+          name: "rewardEntry",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rewardReceipt",
+          name: "rewardDistributor",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "payer",
           isMut: true,
           isSigner: true,
         },
-      ],
-      args: [
         {
-          name: "allowed",
-          type: "bool",
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
         },
       ],
+      args: [],
     },
     {
-      name: "initRewardDistributor",
+      name: "closeRewardEntry",
       accounts: [
         {
           name: "rewardDistributor",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "rewardEntry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "stakePool",
+          name: "stakeEntry",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "rewardMint",
+          name: "rentPayer",
           isMut: true,
           isSigner: false,
         },
@@ -3761,62 +6869,83 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: true,
           isSigner: true,
         },
+      ],
+      args: [],
+    },
+    {
+      name: "updateRewardEntry",
+      accounts: [
         {
-          name: "payer",
+          name: "rewardEntry",
           isMut: true,
-          isSigner: true,
+          isSigner: false,
         },
         {
-          name: "tokenProgram",
+          name: "rewardDistributor",
           isMut: false,
           isSigner: false,
         },
         {
-          name: "systemProgram",
+          name: "authority",
           isMut: false,
-          isSigner: false,
+          isSigner: true,
         },
       ],
       args: [
         {
           name: "ix",
           type: {
-            defined: "InitRewardDistributorIx",
+            defined: "UpdateRewardEntryIx",
           },
         },
       ],
     },
     {
-      name: "updateRewardDistributor",
+      name: "syncRewardEntryMultiplier",
       accounts: [
         {
-          name: "rewardDistributor",
+          name: "rewardEntry",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authority",
+          name: "rewardDistributor",
           isMut: false,
-          isSigner: true,
+          isSigner: false,
+        },
+        {
+          name: "stakeEntry",
+          isMut: false,
+          isSigner: false,
         },
       ],
       args: [
         {
           name: "ix",
           type: {
-            defined: "UpdateRewardDistributorIx",
+            defined: "SyncRewardEntryMultiplierIx",
           },
         },
       ],
     },
     {
-      name: "closeRewardDistributor",
+      name: "claimRewards",
       accounts: [
+        {
+          name: "rewardEntry",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "rewardDistributor",
           isMut: true,
           isSigner: false,
         },
+        {
+          name: "stakeEntry",
+          isMut: true,
+          isSigner: false,
+        },
         {
           name: "stakePool",
           isMut: false,
@@ -3828,17 +6957,17 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isSigner: false,
         },
         {
-          name: "rewardDistributorTokenAccount",
+          name: "userRewardMintTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "authorityTokenAccount",
+          name: "rewardDistributorTokenAccount",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "signer",
+          name: "user",
           isMut: true,
           isSigner: true,
         },
@@ -3847,262 +6976,542 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
           isMut: false,
           isSigner: false,
         },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+          isOptional: true,
+        },
       ],
       args: [],
     },
     {
-      name: "reclaimFunds",
+      name: "initPaymentInfo",
       accounts: [
         {
-          name: "rewardDistributor",
+          name: "paymentInfo",
           isMut: true,
           isSigner: false,
         },
         {
-          name: "rewardDistributorTokenAccount",
+          name: "payer",
           isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "authorityTokenAccount",
+          name: "ix",
+          type: {
+            defined: "InitPaymentInfoIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "updatePaymentInfo",
+      accounts: [
+        {
+          name: "paymentInfo",
           isMut: true,
           isSigner: false,
         },
         {
           name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
           isMut: true,
           isSigner: true,
         },
         {
-          name: "tokenProgram",
+          name: "systemProgram",
           isMut: false,
           isSigner: false,
         },
       ],
       args: [
         {
-          name: "amount",
-          type: "u64",
+          name: "ix",
+          type: {
+            defined: "UpdatePaymentInfoIx",
+          },
         },
       ],
     },
     {
-      name: "initRewardEntry",
-      accounts: [
-        {
-          name: "rewardEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "stakeEntry",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "rewardDistributor",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+      name: "closePaymentInfo",
+      accounts: [
+        {
+          name: "paymentInfo",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+  ],
+  accounts: [
+    {
+      name: "stakeAuthorizationRecord",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "expiresAt",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "maxStakeSeconds",
+            type: {
+              option: "u128",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "stakeDenyRecord",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "leaderboard",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "kind",
+            type: "u8",
+          },
+          {
+            name: "rewardDistributor",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "size",
+            type: "u8",
+          },
+          {
+            name: "entries",
+            type: {
+              vec: {
+                defined: "LeaderboardEntry",
+              },
+            },
+          },
+          {
+            name: "season",
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "paymentInfo",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "identifier",
+            type: "string",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "paymentShares",
+            type: {
+              vec: {
+                defined: "PaymentShare",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "rewardEntry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "stakeEntry",
+            type: "publicKey",
+          },
+          {
+            name: "rewardDistributor",
+            type: "publicKey",
+          },
+          {
+            name: "rewardSecondsReceived",
+            type: "u128",
+          },
+          {
+            name: "multiplier",
+            type: "u64",
+          },
+          {
+            name: "rentPayer",
+            type: "publicKey",
+          },
+          {
+            name: "season",
+            type: "u32",
+          },
+          {
+            name: "swappedInStakeSeconds",
+            type: "u128",
+          },
+        ],
+      },
     },
     {
-      name: "closeRewardEntry",
-      accounts: [
-        {
-          name: "rewardDistributor",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "rewardEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "rewardDistributor",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "stakePool",
+            type: "publicKey",
+          },
+          {
+            name: "kind",
+            type: "u8",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "rewardMint",
+            type: "publicKey",
+          },
+          {
+            name: "rewardAmount",
+            type: "u64",
+          },
+          {
+            name: "rewardDurationSeconds",
+            type: "u128",
+          },
+          {
+            name: "rewardsIssued",
+            type: "u128",
+          },
+          {
+            name: "defaultMultiplier",
+            type: "u64",
+          },
+          {
+            name: "multiplierDecimals",
+            type: "u8",
+          },
+          {
+            name: "claimRewardsPaymentInfo",
+            type: "publicKey",
+          },
+          {
+            name: "maxRewardSecondsReceived",
+            type: {
+              option: "u128",
+            },
+          },
+          {
+            name: "multiplierRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+          {
+            name: "rewardsFunded",
+            type: "u128",
+          },
+        ],
+      },
     },
     {
-      name: "updateRewardEntry",
-      accounts: [
-        {
-          name: "rewardEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "rewardDistributor",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "UpdateRewardEntryIx",
+      name: "rewardBonusRule",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "rewardDistributor",
+            type: "publicKey",
+          },
+          {
+            name: "collection",
+            type: "publicKey",
+          },
+          {
+            name: "multiplier",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "receiptManager",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "stakePool",
+            type: "publicKey",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "requiredStakeSeconds",
+            type: "u128",
+          },
+          {
+            name: "stakeSecondsToUse",
+            type: "u128",
+          },
+          {
+            name: "claimedReceiptsCounter",
+            type: "u128",
+          },
+          {
+            name: "requiresAuthorization",
+            type: "bool",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "paymentShares",
+            type: {
+              vec: {
+                defined: "PaymentShare",
+              },
+            },
           },
-        },
-      ],
-    },
-    {
-      name: "claimRewards",
-      accounts: [
-        {
-          name: "rewardEntry",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "rewardDistributor",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "stakeEntry",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "stakePool",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "rewardMint",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "userRewardMintTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "rewardDistributorTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [],
+          {
+            name: "claimActionPaymentInfo",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "maxClaimedReceipts",
+            type: {
+              option: "u128",
+            },
+          },
+        ],
+      },
     },
     {
-      name: "initPaymentInfo",
-      accounts: [
-        {
-          name: "paymentInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "InitPaymentInfoIx",
+      name: "rewardReceipt",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
           },
-        },
-      ],
+          {
+            name: "stakeEntry",
+            type: "publicKey",
+          },
+          {
+            name: "receiptManager",
+            type: "publicKey",
+          },
+          {
+            name: "target",
+            type: "publicKey",
+          },
+          {
+            name: "allowed",
+            type: "bool",
+          },
+          {
+            name: "rentPayer",
+            type: "publicKey",
+          },
+        ],
+      },
     },
     {
-      name: "updatePaymentInfo",
-      accounts: [
-        {
-          name: "paymentInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "UpdatePaymentInfoIx",
+      name: "stakeBooster",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
           },
-        },
-      ],
+          {
+            name: "stakePool",
+            type: "publicKey",
+          },
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "paymentShares",
+            type: {
+              vec: {
+                defined: "PaymentShare",
+              },
+            },
+          },
+          {
+            name: "boostSeconds",
+            type: "u128",
+          },
+          {
+            name: "startTimeSeconds",
+            type: "i64",
+          },
+          {
+            name: "boostActionPaymentInfo",
+            type: "publicKey",
+          },
+        ],
+      },
     },
     {
-      name: "closePaymentInfo",
-      accounts: [
-        {
-          name: "paymentInfo",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: true,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "userEscrow",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "delegations",
+            type: "u64",
+          },
+        ],
+      },
     },
-  ],
-  accounts: [
     {
-      name: "stakeAuthorizationRecord",
+      name: "userStakeRegistry",
       type: {
         kind: "struct",
         fields: [
@@ -4115,14 +7524,34 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "publicKey",
           },
           {
-            name: "mint",
-            type: "publicKey",
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "stakeEntries",
+            type: {
+              vec: {
+                defined: "UserStakeRegistryEntry",
+              },
+            },
+          },
+          {
+            name: "totalStaked",
+            type: "u64",
+          },
+          {
+            name: "totalStakeSeconds",
+            type: "u128",
+          },
+          {
+            name: "permitNonce",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "paymentInfo",
+      name: "stakeEntry",
       type: {
         kind: "struct",
         fields: [
@@ -4131,124 +7560,177 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "u8",
           },
           {
-            name: "authority",
-            type: "publicKey",
+            name: "kind",
+            type: "u8",
           },
           {
-            name: "identifier",
-            type: "string",
+            name: "pool",
+            type: "publicKey",
           },
           {
-            name: "paymentAmount",
+            name: "amount",
             type: "u64",
           },
           {
-            name: "paymentMint",
+            name: "stakeMint",
             type: "publicKey",
           },
           {
-            name: "paymentShares",
+            name: "lastStaker",
+            type: "publicKey",
+          },
+          {
+            name: "lastStakedAt",
+            type: "i64",
+          },
+          {
+            name: "lastUpdatedAt",
+            type: "i64",
+          },
+          {
+            name: "totalStakeSeconds",
+            type: "u128",
+          },
+          {
+            name: "usedStakeSeconds",
+            type: "u128",
+          },
+          {
+            name: "cooldownStartSeconds",
             type: {
-              vec: {
-                defined: "PaymentShare",
-              },
+              option: "i64",
             },
           },
-        ],
-      },
-    },
-    {
-      name: "rewardEntry",
-      type: {
-        kind: "struct",
-        fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "multiplierStakeSeconds",
+            type: {
+              option: "u128",
+            },
           },
           {
-            name: "stakeEntry",
-            type: "publicKey",
+            name: "multiplierBasisPoints",
+            type: {
+              option: "u64",
+            },
           },
           {
-            name: "rewardDistributor",
-            type: "publicKey",
+            name: "denied",
+            type: "bool",
           },
           {
-            name: "rewardSecondsReceived",
-            type: "u128",
+            name: "stakeAuthorizationRecord",
+            type: {
+              option: "publicKey",
+            },
           },
           {
-            name: "multiplier",
-            type: "u64",
+            name: "authorizationExpiresAt",
+            type: {
+              option: "i64",
+            },
           },
-        ],
-      },
-    },
-    {
-      name: "rewardDistributor",
-      type: {
-        kind: "struct",
-        fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "authorizationMaxStakeSeconds",
+            type: {
+              option: "u128",
+            },
           },
           {
-            name: "stakePool",
+            name: "authorizationStakeSeconds",
+            type: "u128",
+          },
+          {
+            name: "rentPayer",
             type: "publicKey",
           },
           {
-            name: "kind",
-            type: "u8",
+            name: "unlockAt",
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "authority",
-            type: "publicKey",
+            name: "lockMultiplierBasisPoints",
+            type: {
+              option: "u64",
+            },
           },
           {
-            name: "identifier",
-            type: "u64",
+            name: "loyaltyRamp",
+            type: {
+              option: {
+                defined: "LoyaltyRamp",
+              },
+            },
           },
           {
-            name: "rewardMint",
-            type: "publicKey",
+            name: "timedMultipliers",
+            type: {
+              array: [
+                {
+                  option: {
+                    defined: "TimedMultiplier",
+                  },
+                },
+                4,
+              ],
+            },
           },
           {
-            name: "rewardAmount",
-            type: "u64",
+            name: "stakeSet",
+            type: {
+              option: "publicKey",
+            },
           },
           {
-            name: "rewardDurationSeconds",
+            name: "stakeSetMultiplierBasisPoints",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "team",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "teamStakeSecondsCheckpoint",
             type: "u128",
           },
           {
-            name: "rewardsIssued",
+            name: "teamPendingStakeSeconds",
             type: "u128",
           },
           {
-            name: "defaultMultiplier",
-            type: "u64",
+            name: "season",
+            type: "u32",
           },
           {
-            name: "multiplierDecimals",
-            type: "u8",
+            name: "previousSeasonStakeSeconds",
+            type: "u128",
           },
           {
-            name: "claimRewardsPaymentInfo",
-            type: "publicKey",
+            name: "unstakedAt",
+            type: {
+              option: "i64",
+            },
           },
           {
-            name: "maxRewardSecondsReceived",
+            name: "warmupEndsAt",
             type: {
-              option: "u128",
+              option: "i64",
             },
           },
+          {
+            name: "swappedInStakeSeconds",
+            type: "u128",
+          },
         ],
       },
     },
     {
-      name: "receiptManager",
+      name: "stakePool",
       type: {
         kind: "struct",
         fields: [
@@ -4257,64 +7739,200 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "u8",
           },
           {
-            name: "stakePool",
+            name: "authority",
             type: "publicKey",
           },
           {
-            name: "authority",
+            name: "totalStaked",
+            type: "u32",
+          },
+          {
+            name: "resetOnUnstake",
+            type: "bool",
+          },
+          {
+            name: "cooldownSeconds",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "minStakeSeconds",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "endDate",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "stakePaymentInfo",
             type: "publicKey",
           },
           {
-            name: "requiredStakeSeconds",
-            type: "u128",
+            name: "unstakePaymentInfo",
+            type: "publicKey",
           },
           {
-            name: "stakeSecondsToUse",
-            type: "u128",
+            name: "requiresAuthorization",
+            type: "bool",
           },
           {
-            name: "claimedReceiptsCounter",
-            type: "u128",
+            name: "allowedCreators",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "allowedCollections",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "identifier",
+            type: "string",
+          },
+          {
+            name: "passMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "feeExemptMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "feeExemptBoostAndClaim",
+            type: "bool",
+          },
+          {
+            name: "allowedMintsRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+          {
+            name: "traitRulesRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+          {
+            name: "traitRules",
+            type: {
+              vec: {
+                defined: "TraitRule",
+              },
+            },
+          },
+          {
+            name: "deniedMints",
+            type: "u32",
+          },
+          {
+            name: "permitSigner",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "maxStaked",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "maxStakedPerWallet",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "lockTiers",
+            type: {
+              vec: {
+                defined: "LockTier",
+              },
+            },
+          },
+          {
+            name: "loyaltyRamp",
+            type: {
+              option: {
+                defined: "LoyaltyRamp",
+              },
+            },
+          },
+          {
+            name: "boostWindows",
+            type: {
+              vec: {
+                defined: "BoostWindow",
+              },
+            },
           },
           {
-            name: "requiresAuthorization",
-            type: "bool",
+            name: "stakeSetRules",
+            type: {
+              vec: {
+                defined: "StakeSetRule",
+              },
+            },
           },
           {
-            name: "paymentAmount",
-            type: "u64",
+            name: "season",
+            type: "u32",
           },
           {
-            name: "paymentMint",
-            type: "publicKey",
+            name: "seasonStartedAt",
+            type: "i64",
           },
           {
-            name: "paymentShares",
+            name: "stakeSecondsDecay",
             type: {
-              vec: {
-                defined: "PaymentShare",
+              option: {
+                defined: "StakeSecondsDecay",
               },
             },
           },
           {
-            name: "claimActionPaymentInfo",
-            type: "publicKey",
+            name: "warmupSeconds",
+            type: {
+              option: "u32",
+            },
           },
           {
-            name: "name",
-            type: "string",
+            name: "accrueDuringCooldown",
+            type: "bool",
           },
           {
-            name: "maxClaimedReceipts",
+            name: "swapStakeBasisPoints",
             type: {
-              option: "u128",
+              option: "u64",
             },
           },
         ],
       },
     },
     {
-      name: "rewardReceipt",
+      name: "stakeSet",
       type: {
         kind: "struct",
         fields: [
@@ -4323,26 +7941,32 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "u8",
           },
           {
-            name: "stakeEntry",
+            name: "pool",
             type: "publicKey",
           },
           {
-            name: "receiptManager",
+            name: "user",
             type: "publicKey",
           },
           {
-            name: "target",
-            type: "publicKey",
+            name: "identifier",
+            type: "u64",
           },
           {
-            name: "allowed",
-            type: "bool",
+            name: "multiplierBasisPoints",
+            type: "u64",
+          },
+          {
+            name: "stakeEntries",
+            type: {
+              vec: "publicKey",
+            },
           },
         ],
       },
     },
     {
-      name: "stakeBooster",
+      name: "team",
       type: {
         kind: "struct",
         fields: [
@@ -4351,7 +7975,11 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "u8",
           },
           {
-            name: "stakePool",
+            name: "pool",
+            type: "publicKey",
+          },
+          {
+            name: "authority",
             type: "publicKey",
           },
           {
@@ -4359,190 +7987,155 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "u64",
           },
           {
-            name: "paymentAmount",
-            type: "u64",
+            name: "memberCount",
+            type: "u32",
           },
           {
-            name: "paymentMint",
+            name: "totalStakeSeconds",
+            type: "u128",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "StakePermit",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pool",
             type: "publicKey",
           },
           {
-            name: "paymentShares",
-            type: {
-              vec: {
-                defined: "PaymentShare",
-              },
-            },
+            name: "mint",
+            type: "publicKey",
           },
           {
-            name: "boostSeconds",
-            type: "u128",
+            name: "user",
+            type: "publicKey",
           },
           {
-            name: "startTimeSeconds",
+            name: "expiresAt",
             type: "i64",
           },
           {
-            name: "boostActionPaymentInfo",
-            type: "publicKey",
+            name: "nonce",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "userEscrow",
+      name: "Trait",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "traitType",
+            type: "string",
           },
           {
-            name: "user",
-            type: "publicKey",
+            name: "value",
+            type: "string",
           },
         ],
       },
     },
     {
-      name: "stakeEntry",
+      name: "TraitRule",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "kind",
-            type: "u8",
-          },
-          {
-            name: "pool",
-            type: "publicKey",
-          },
-          {
-            name: "amount",
-            type: "u64",
-          },
-          {
-            name: "stakeMint",
-            type: "publicKey",
-          },
-          {
-            name: "lastStaker",
-            type: "publicKey",
-          },
-          {
-            name: "lastStakedAt",
-            type: "i64",
-          },
-          {
-            name: "lastUpdatedAt",
-            type: "i64",
-          },
-          {
-            name: "totalStakeSeconds",
-            type: "u128",
-          },
-          {
-            name: "usedStakeSeconds",
-            type: "u128",
+            name: "matchAll",
+            type: "bool",
           },
           {
-            name: "cooldownStartSeconds",
+            name: "traits",
             type: {
-              option: "i64",
+              vec: {
+                defined: "Trait",
+              },
             },
           },
+        ],
+      },
+    },
+    {
+      name: "TraitProof",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "multiplierStakeSeconds",
+            name: "traits",
             type: {
-              option: "u128",
+              vec: {
+                defined: "Trait",
+              },
             },
           },
           {
-            name: "multiplierBasisPoints",
+            name: "proof",
             type: {
-              option: "u64",
+              vec: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
             },
           },
         ],
       },
     },
     {
-      name: "stakePool",
+      name: "InitLeaderboardIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
-          },
-          {
-            name: "authority",
-            type: "publicKey",
-          },
-          {
-            name: "totalStaked",
-            type: "u32",
-          },
-          {
-            name: "resetOnUnstake",
-            type: "bool",
+            name: "identifier",
+            type: "u64",
           },
           {
-            name: "cooldownSeconds",
-            type: {
-              option: "u32",
-            },
+            name: "kind",
+            type: "u8",
           },
           {
-            name: "minStakeSeconds",
+            name: "rewardDistributor",
             type: {
-              option: "u32",
+              option: "publicKey",
             },
           },
           {
-            name: "endDate",
-            type: {
-              option: "i64",
-            },
+            name: "size",
+            type: "u8",
           },
+        ],
+      },
+    },
+    {
+      name: "LeaderboardEntry",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "stakePaymentInfo",
+            name: "stakeEntry",
             type: "publicKey",
           },
           {
-            name: "unstakePaymentInfo",
+            name: "user",
             type: "publicKey",
           },
           {
-            name: "requiresAuthorization",
-            type: "bool",
-          },
-          {
-            name: "allowedCreators",
-            type: {
-              vec: "publicKey",
-            },
-          },
-          {
-            name: "allowedCollections",
-            type: {
-              vec: "publicKey",
-            },
-          },
-          {
-            name: "identifier",
-            type: "string",
+            name: "value",
+            type: "u128",
           },
         ],
       },
     },
-  ],
-  types: [
     {
       name: "InitPaymentInfoIx",
       type: {
@@ -4619,6 +8212,22 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
         ],
       },
     },
+    {
+      name: "InitRewardBonusRuleIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "collection",
+            type: "publicKey",
+          },
+          {
+            name: "multiplier",
+            type: "u64",
+          },
+        ],
+      },
+    },
     {
       name: "InitRewardDistributorIx",
       type: {
@@ -4664,6 +8273,17 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             name: "claimRewardsPaymentInfo",
             type: "publicKey",
           },
+          {
+            name: "multiplierRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
         ],
       },
     },
@@ -4698,6 +8318,40 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             name: "claimRewardsPaymentInfo",
             type: "publicKey",
           },
+          {
+            name: "multiplierRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SyncRewardEntryMultiplierIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "multiplier",
+            type: "u64",
+          },
+          {
+            name: "proof",
+            type: {
+              vec: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
         ],
       },
     },
@@ -4867,50 +8521,336 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "i64",
           },
           {
-            name: "boostActionPaymentInfo",
-            type: "publicKey",
+            name: "boostActionPaymentInfo",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "UpdateStakeBoosterIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "paymentAmount",
+            type: "u64",
+          },
+          {
+            name: "paymentMint",
+            type: "publicKey",
+          },
+          {
+            name: "paymentShares",
+            type: {
+              vec: {
+                defined: "PaymentShare",
+              },
+            },
+          },
+          {
+            name: "boostSeconds",
+            type: "u128",
+          },
+          {
+            name: "startTimeSeconds",
+            type: "i64",
+          },
+          {
+            name: "boostActionPaymentInfo",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "UserStakeRegistryEntry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "stakeEntry",
+            type: "publicKey",
+          },
+          {
+            name: "syncedStakeSeconds",
+            type: "u128",
+          },
+        ],
+      },
+    },
+    {
+      name: "TimedMultiplier",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "multiplierBasisPoints",
+            type: "u64",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitPoolIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "allowedCollections",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "allowedCreators",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "requiresAuthorization",
+            type: "bool",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "resetOnUnstake",
+            type: "bool",
+          },
+          {
+            name: "cooldownSeconds",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "minStakeSeconds",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "endDate",
+            type: {
+              option: "i64",
+            },
+          },
+          {
+            name: "stakePaymentInfo",
+            type: "publicKey",
+          },
+          {
+            name: "unstakePaymentInfo",
+            type: "publicKey",
+          },
+          {
+            name: "identifier",
+            type: "string",
+          },
+          {
+            name: "passMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "feeExemptMint",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "feeExemptBoostAndClaim",
+            type: "bool",
+          },
+          {
+            name: "allowedMintsRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+          {
+            name: "traitRulesRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
+          },
+          {
+            name: "traitRules",
+            type: {
+              vec: {
+                defined: "TraitRule",
+              },
+            },
+          },
+          {
+            name: "permitSigner",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "maxStaked",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "maxStakedPerWallet",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "lockTiers",
+            type: {
+              vec: {
+                defined: "LockTier",
+              },
+            },
+          },
+          {
+            name: "loyaltyRamp",
+            type: {
+              option: {
+                defined: "LoyaltyRamp",
+              },
+            },
+          },
+          {
+            name: "boostWindows",
+            type: {
+              vec: {
+                defined: "BoostWindow",
+              },
+            },
+          },
+          {
+            name: "stakeSetRules",
+            type: {
+              vec: {
+                defined: "StakeSetRule",
+              },
+            },
+          },
+          {
+            name: "stakeSecondsDecay",
+            type: {
+              option: {
+                defined: "StakeSecondsDecay",
+              },
+            },
+          },
+          {
+            name: "warmupSeconds",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "accrueDuringCooldown",
+            type: "bool",
+          },
+          {
+            name: "swapStakeBasisPoints",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "LockTier",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "lockSeconds",
+            type: "u32",
+          },
+          {
+            name: "multiplierBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LoyaltyRamp",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "intervalSeconds",
+            type: "u32",
+          },
+          {
+            name: "incrementBasisPoints",
+            type: "u64",
+          },
+          {
+            name: "maxBonusBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "StakeSecondsDecay",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "kind",
+            type: "u8",
+          },
+          {
+            name: "periodSeconds",
+            type: "u32",
           },
         ],
       },
     },
     {
-      name: "UpdateStakeBoosterIx",
+      name: "BoostWindow",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "paymentAmount",
-            type: "u64",
-          },
-          {
-            name: "paymentMint",
-            type: "publicKey",
-          },
-          {
-            name: "paymentShares",
-            type: {
-              vec: {
-                defined: "PaymentShare",
-              },
-            },
-          },
-          {
-            name: "boostSeconds",
-            type: "u128",
+            name: "start",
+            type: "i64",
           },
           {
-            name: "startTimeSeconds",
+            name: "end",
             type: "i64",
           },
           {
-            name: "boostActionPaymentInfo",
-            type: "publicKey",
+            name: "multiplierBasisPoints",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "InitPoolIx",
+      name: "UpdatePoolIx",
       type: {
         kind: "struct",
         fields: [
@@ -4965,66 +8905,192 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
             type: "publicKey",
           },
           {
-            name: "identifier",
-            type: "string",
+            name: "passMint",
+            type: {
+              option: "publicKey",
+            },
           },
-        ],
-      },
-    },
-    {
-      name: "UpdatePoolIx",
-      type: {
-        kind: "struct",
-        fields: [
           {
-            name: "allowedCollections",
+            name: "feeExemptMint",
             type: {
-              vec: "publicKey",
+              option: "publicKey",
             },
           },
           {
-            name: "allowedCreators",
+            name: "feeExemptBoostAndClaim",
+            type: "bool",
+          },
+          {
+            name: "allowedMintsRoot",
             type: {
-              vec: "publicKey",
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
             },
           },
           {
-            name: "requiresAuthorization",
-            type: "bool",
+            name: "traitRulesRoot",
+            type: {
+              option: {
+                array: [
+                  "u8",
+                  32,
+                ],
+              },
+            },
           },
           {
-            name: "authority",
-            type: "publicKey",
+            name: "traitRules",
+            type: {
+              vec: {
+                defined: "TraitRule",
+              },
+            },
           },
           {
-            name: "resetOnUnstake",
-            type: "bool",
+            name: "permitSigner",
+            type: {
+              option: "publicKey",
+            },
           },
           {
-            name: "cooldownSeconds",
+            name: "maxStaked",
             type: {
               option: "u32",
             },
           },
           {
-            name: "minStakeSeconds",
+            name: "maxStakedPerWallet",
             type: {
               option: "u32",
             },
           },
           {
-            name: "endDate",
+            name: "lockTiers",
             type: {
-              option: "i64",
+              vec: {
+                defined: "LockTier",
+              },
             },
           },
           {
-            name: "stakePaymentInfo",
-            type: "publicKey",
+            name: "loyaltyRamp",
+            type: {
+              option: {
+                defined: "LoyaltyRamp",
+              },
+            },
           },
           {
-            name: "unstakePaymentInfo",
-            type: "publicKey",
+            name: "boostWindows",
+            type: {
+              vec: {
+                defined: "BoostWindow",
+              },
+            },
+          },
+          {
+            name: "stakeSetRules",
+            type: {
+              vec: {
+                defined: "StakeSetRule",
+              },
+            },
+          },
+          {
+            name: "stakeSecondsDecay",
+            type: {
+              option: {
+                defined: "StakeSecondsDecay",
+              },
+            },
+          },
+          {
+            name: "warmupSeconds",
+            type: {
+              option: "u32",
+            },
+          },
+          {
+            name: "accrueDuringCooldown",
+            type: "bool",
+          },
+          {
+            name: "swapStakeBasisPoints",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "FormStakeSetIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "identifier",
+            type: "u64",
+          },
+          {
+            name: "rule",
+            type: "u8",
+          },
+          {
+            name: "traitProofs",
+            type: {
+              vec: {
+                defined: "TraitProof",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "StakeSetRule",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "collections",
+            type: {
+              vec: "publicKey",
+            },
+          },
+          {
+            name: "traitMatch",
+            type: {
+              option: {
+                defined: "Trait",
+              },
+            },
+          },
+          {
+            name: "size",
+            type: "u8",
+          },
+          {
+            name: "multiplierBasisPoints",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "LeaderboardKind",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "TotalStakeSeconds",
+          },
+          {
+            name: "RewardSecondsReceived",
           },
         ],
       },
@@ -5052,6 +9118,20 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
         ],
       },
     },
+    {
+      name: "StakeSecondsDecayKind",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "HalfLife",
+          },
+          {
+            name: "Linear",
+          },
+        ],
+      },
+    },
   ],
   errors: [
     {
@@ -5074,6 +9154,11 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       name: "InvalidEscrow",
       msg: "Mismatched user and escrow",
     },
+    {
+      code: 6004,
+      name: "InvalidRentPayer",
+      msg: "Invalid rent payer",
+    },
     {
       code: 6010,
       name: "InvalidUserStakeMintTokenAccount",
@@ -5099,6 +9184,16 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       name: "CannotClosePoolWithStakedEntries",
       msg: "Cannot close staked entry",
     },
+    {
+      code: 6015,
+      name: "CannotCloseEscrowWithDelegations",
+      msg: "Cannot close escrow with outstanding delegations",
+    },
+    {
+      code: 6016,
+      name: "StakeEntryNotClosed",
+      msg: "Stake entry must be closed first",
+    },
     {
       code: 6020,
       name: "InvalidMintMetadata",
@@ -5119,6 +9214,11 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       name: "InvalidMintMetadataOwner",
       msg: "Mint metadata is owned by the incorrect program",
     },
+    {
+      code: 6024,
+      name: "StakeAuthorizationExpired",
+      msg: "Stake authorization has expired",
+    },
     {
       code: 6030,
       name: "InvalidPaymentMint",
@@ -5154,6 +9254,11 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       name: "CooldownSecondRemaining",
       msg: "Token still has some cooldown seconds remaining",
     },
+    {
+      code: 6041,
+      name: "StakeEntryNotInCooldown",
+      msg: "Stake entry is not in cooldown",
+    },
     {
       code: 6050,
       name: "StakePoolHasEnded",
@@ -5164,6 +9269,41 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       name: "MinStakeSecondsNotSatisfied",
       msg: "Minimum stake seconds not satisfied",
     },
+    {
+      code: 6052,
+      name: "MaxStakedReached",
+      msg: "Stake pool has reached its maximum staked",
+    },
+    {
+      code: 6053,
+      name: "MaxStakedPerWalletReached",
+      msg: "User has reached the maximum staked per wallet",
+    },
+    {
+      code: 6054,
+      name: "InvalidLockTier",
+      msg: "Invalid lock tier",
+    },
+    {
+      code: 6055,
+      name: "StakeEntryLocked",
+      msg: "Stake entry is still locked",
+    },
+    {
+      code: 6056,
+      name: "InvalidStakeSecondsDecay",
+      msg: "Invalid stake seconds decay",
+    },
+    {
+      code: 6057,
+      name: "InvalidLoyaltyRamp",
+      msg: "Invalid loyalty ramp",
+    },
+    {
+      code: 6058,
+      name: "InvalidBoostWindow",
+      msg: "Invalid boost window",
+    },
     {
       code: 6060,
       name: "CannotBoostUnstakedToken",
@@ -5319,5 +9459,155 @@ export const IDL: SolanaNftProgramsRewardsCenter = {
       name: "MaxRewardSecondsClaimed",
       msg: "Max reward seconds claimed",
     },
+    {
+      code: 6104,
+      name: "InvalidRewardMultiplierProof",
+      msg: "Invalid reward multiplier proof",
+    },
+    {
+      code: 6105,
+      name: "InvalidRewardBonusRule",
+      msg: "Invalid reward bonus rule",
+    },
+    {
+      code: 6106,
+      name: "InvalidRewardBonusEvidence",
+      msg: "Invalid reward bonus evidence",
+    },
+    {
+      code: 6110,
+      name: "InvalidPassTokenAccount",
+      msg: "Invalid pass token account",
+    },
+    {
+      code: 6111,
+      name: "InvalidPassMintMetadata",
+      msg: "Invalid pass mint metadata",
+    },
+    {
+      code: 6120,
+      name: "InvalidTraitProof",
+      msg: "Invalid trait proof",
+    },
+    {
+      code: 6121,
+      name: "TraitRulesNotSatisfied",
+      msg: "Mint traits do not satisfy the pool trait rules",
+    },
+    {
+      code: 6130,
+      name: "MintDenied",
+      msg: "Mint is denied in this pool",
+    },
+    {
+      code: 6131,
+      name: "InvalidStakeDenyRecord",
+      msg: "Invalid stake deny record",
+    },
+    {
+      code: 6140,
+      name: "InvalidStakePermit",
+      msg: "Invalid stake permit",
+    },
+    {
+      code: 6141,
+      name: "StakePermitExpired",
+      msg: "Stake permit has expired",
+    },
+    {
+      code: 6142,
+      name: "StakePermitNonceUsed",
+      msg: "Stake permit nonce already used",
+    },
+    {
+      code: 6150,
+      name: "InvalidTimedMultiplierExpiry",
+      msg: "Timed multiplier must expire in the future",
+    },
+    {
+      code: 6151,
+      name: "TimedMultipliersFull",
+      msg: "No free timed multiplier slots on stake entry",
+    },
+    {
+      code: 6160,
+      name: "InvalidStakeSetRule",
+      msg: "Invalid stake set rule",
+    },
+    {
+      code: 6161,
+      name: "InvalidStakeSetMember",
+      msg: "Invalid stake set member",
+    },
+    {
+      code: 6162,
+      name: "InvalidStakeSet",
+      msg: "Invalid stake set",
+    },
+    {
+      code: 6170,
+      name: "InvalidTeam",
+      msg: "Invalid team",
+    },
+    {
+      code: 6171,
+      name: "StakeEntryAlreadyInTeam",
+      msg: "Stake entry is already in a team",
+    },
+    {
+      code: 6172,
+      name: "StakeEntryNotInTeam",
+      msg: "Stake entry is not in this team",
+    },
+    {
+      code: 6173,
+      name: "CannotCloseTeamWithMembers",
+      msg: "Cannot close team with members",
+    },
+    {
+      code: 6180,
+      name: "InvalidLeaderboard",
+      msg: "Invalid leaderboard",
+    },
+    {
+      code: 6181,
+      name: "InvalidLeaderboardKind",
+      msg: "Invalid leaderboard kind",
+    },
+    {
+      code: 6182,
+      name: "InvalidLeaderboardSize",
+      msg: "Invalid leaderboard size",
+    },
+    {
+      code: 6183,
+      name: "LeaderboardSizeTooLarge",
+      msg: "Leaderboard size exceeds the maximum account size",
+    },
+    {
+      code: 6184,
+      name: "LeaderboardSeasonMismatch",
+      msg: "Stake entry is not in the leaderboard's season",
+    },
+    {
+      code: 6190,
+      name: "StakeSwapDisabled",
+      msg: "Stake swaps are disabled for this pool",
+    },
+    {
+      code: 6191,
+      name: "InvalidStakeSwap",
+      msg: "Invalid stake swap",
+    },
+    {
+      code: 6192,
+      name: "InvalidStakeSwapRewardEntry",
+      msg: "Invalid stake swap reward entry",
+    },
+    {
+      code: 6193,
+      name: "StakeSwapSourceNotUnstaked",
+      msg: "Stake swap source entry must be unstaked later in the transaction",
+    },
   ],
 };
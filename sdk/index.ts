@@ -3,6 +3,7 @@ export * from "./api";
 export * from "./authorization";
 export * from "./constants";
 export * from "./idl/solana_nft_programs_rewards_center";
+export * from "./merkle";
 export * from "./payment";
 export * from "./pda";
 export * from "./rewardDistribution";
@@ -0,0 +1,120 @@
+import { keccak_256 } from "@noble/hashes/sha3";
+import type { PublicKey } from "@solana/web3.js";
+
+export type Trait = {
+  traitType: string;
+  value: string;
+};
+
+/**
+ * Leaf for a mint in a pool's allowed mints root
+ *
+ * @param mintId
+ * @returns
+ */
+export const mintLeaf = (mintId: PublicKey): Buffer => {
+  return Buffer.from(keccak_256(mintId.toBuffer()));
+};
+
+/**
+ * Leaf for a mint and its traits in a pool's trait rules root
+ *
+ * @param mintId
+ * @param traits
+ * @returns
+ */
+export const traitsLeaf = (mintId: PublicKey, traits: Trait[]): Buffer => {
+  // borsh encoding of Vec<Trait>
+  const encodeString = (s: string) => {
+    const bytes = Buffer.from(s, "utf8");
+    const length = Buffer.alloc(4);
+    length.writeUInt32LE(bytes.length);
+    return Buffer.concat([length, bytes]);
+  };
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(traits.length);
+  return Buffer.from(
+    keccak_256(
+      Buffer.concat([
+        mintId.toBuffer(),
+        length,
+        ...traits.map((t) =>
+          Buffer.concat([encodeString(t.traitType), encodeString(t.value)]),
+        ),
+      ]),
+    ),
+  );
+};
+
+/**
+ * Leaf for a mint and its multiplier in a reward distributor's multiplier root
+ *
+ * @param mintId
+ * @param multiplier
+ * @returns
+ */
+export const multiplierLeaf = (
+  mintId: PublicKey,
+  multiplier: number,
+): Buffer => {
+  const multiplierBytes = Buffer.alloc(8);
+  multiplierBytes.writeBigUInt64LE(BigInt(multiplier));
+  return Buffer.from(
+    keccak_256(Buffer.concat([mintId.toBuffer(), multiplierBytes])),
+  );
+};
+
+const hashPair = (a: Buffer, b: Buffer): Buffer => {
+  // pairs are hashed in sorted order to match the program
+  return Buffer.from(
+    keccak_256(
+      Buffer.compare(a, b) <= 0 ? Buffer.concat([a, b]) : Buffer.concat([b, a]),
+    ),
+  );
+};
+
+const merkleLayers = (leaves: Buffer[]): Buffer[][] => {
+  const layers = [leaves];
+  while (layers[layers.length - 1]!.length > 1) {
+    const layer = layers[layers.length - 1]!;
+    const next: Buffer[] = [];
+    for (let i = 0; i < layer.length; i += 2) {
+      next.push(
+        i + 1 < layer.length ? hashPair(layer[i]!, layer[i + 1]!) : layer[i]!,
+      );
+    }
+    layers.push(next);
+  }
+  return layers;
+};
+
+/**
+ * Root of a merkle tree over the given leaves
+ *
+ * @param leaves
+ * @returns
+ */
+export const merkleRoot = (leaves: Buffer[]): number[] => {
+  const layers = merkleLayers(leaves);
+  return [...layers[layers.length - 1]![0]!];
+};
+
+/**
+ * Proof for the leaf at the given index
+ *
+ * @param leaves
+ * @param index
+ * @returns
+ */
+export const merkleProof = (leaves: Buffer[], index: number): number[][] => {
+  const proof: number[][] = [];
+  const layers = merkleLayers(leaves);
+  for (const layer of layers.slice(0, -1)) {
+    const sibling = layer[index % 2 === 0 ? index + 1 : index - 1];
+    if (sibling) {
+      proof.push([...sibling]);
+    }
+    index = Math.floor(index / 2);
+  }
+  return proof;
+};
@@ -87,6 +87,7 @@ export const withRemainingAccountsForPayment = (
   payer: PublicKey,
   paymentMint: PublicKey,
   paymentTargets: PublicKey[],
+  rewardDistributorTargets?: PublicKey[],
 ): AccountMeta[] => {
   const remainingAccounts = [
     {
@@ -133,13 +134,23 @@ export const withRemainingAccountsForPayment = (
         ),
       );
     }
-    remainingAccounts.push(
-      ...ataIds.map((id) => ({
-        pubkey: id,
+    for (let i = 0; i < ataIds.length; i++) {
+      // distributor treasuries are paid into the distributor's token account
+      if (
+        rewardDistributorTargets?.some((t) => t.equals(paymentTargets[i]!))
+      ) {
+        remainingAccounts.push({
+          pubkey: paymentTargets[i]!,
+          isSigner: false,
+          isWritable: true,
+        });
+      }
+      remainingAccounts.push({
+        pubkey: ataIds[i]!,
         isSigner: false,
         isWritable: true,
-      })),
-    );
+      });
+    }
   }
   return remainingAccounts;
 };
@@ -140,3 +140,95 @@ export const findPaymentInfoId = (identifier: string): PublicKey => {
     REWARDS_CENTER_ADDRESS,
   )[0];
 };
+
+export const STAKE_DENY_RECORD_SEED = "stake-deny";
+export const findStakeDenyRecordId = (
+  stakePoolId: PublicKey,
+  mintId: PublicKey,
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(STAKE_DENY_RECORD_SEED),
+      stakePoolId.toBuffer(),
+      mintId.toBuffer(),
+    ],
+    REWARDS_CENTER_ADDRESS,
+  )[0];
+};
+
+export const USER_STAKE_REGISTRY_SEED = "user-stake-registry";
+export const findUserStakeRegistryId = (
+  stakePoolId: PublicKey,
+  user: PublicKey,
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(USER_STAKE_REGISTRY_SEED),
+      stakePoolId.toBuffer(),
+      user.toBuffer(),
+    ],
+    REWARDS_CENTER_ADDRESS,
+  )[0];
+};
+
+export const REWARD_BONUS_RULE_SEED = "reward-bonus-rule";
+export const findRewardBonusRuleId = (
+  rewardDistributorId: PublicKey,
+  collectionId: PublicKey,
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(REWARD_BONUS_RULE_SEED),
+      rewardDistributorId.toBuffer(),
+      collectionId.toBuffer(),
+    ],
+    REWARDS_CENTER_ADDRESS,
+  )[0];
+};
+
+export const STAKE_SET_SEED = "stake-set";
+export const findStakeSetId = (
+  stakePoolId: PublicKey,
+  user: PublicKey,
+  identifier?: BN,
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(STAKE_SET_SEED),
+      stakePoolId.toBuffer(),
+      user.toBuffer(),
+      (identifier ?? new BN(0)).toArrayLike(Buffer, "le", 8),
+    ],
+    REWARDS_CENTER_ADDRESS,
+  )[0];
+};
+
+export const TEAM_SEED = "team";
+export const findTeamId = (
+  stakePoolId: PublicKey,
+  identifier?: BN,
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(TEAM_SEED),
+      stakePoolId.toBuffer(),
+      (identifier ?? new BN(0)).toArrayLike(Buffer, "le", 8),
+    ],
+    REWARDS_CENTER_ADDRESS,
+  )[0];
+};
+
+export const LEADERBOARD_SEED = "leaderboard";
+export const findLeaderboardId = (
+  stakePoolId: PublicKey,
+  identifier?: BN,
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(LEADERBOARD_SEED),
+      stakePoolId.toBuffer(),
+      (identifier ?? new BN(0)).toArrayLike(Buffer, "le", 8),
+    ],
+    REWARDS_CENTER_ADDRESS,
+  )[0];
+};
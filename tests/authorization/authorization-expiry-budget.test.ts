@@ -0,0 +1,248 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeAuthorizationRecordId,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+const MAX_STAKE_SECONDS = 2;
+let budgetMintId: PublicKey;
+let revokedMintId: PublicKey;
+let expiredMintId: PublicKey;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypairs = [...Array(3)].map(() => Keypair.generate());
+  [budgetMintId, revokedMintId, expiredMintId] = mintKeypairs.map(
+    (k) => k.publicKey
+  ) as [PublicKey, PublicKey, PublicKey];
+  for (const mintKeypair of mintKeypairs) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+});
+
+const updateTotalStakeSeconds = async (mintId: PublicKey) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintId),
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  return fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: true,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.requiresAuthorization).toBe(true);
+});
+
+test("Authorize mints", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const tx = new Transaction();
+  for (const [mintId, expiresAt, maxStakeSeconds] of [
+    [budgetMintId, null, new BN(MAX_STAKE_SECONDS)],
+    [revokedMintId, new BN(Math.floor(Date.now() / 1000) + 600), null],
+    [expiredMintId, new BN(Math.floor(Date.now() / 1000) - 600), null],
+  ] as [PublicKey, BN | null, BN | null][]) {
+    tx.add(
+      await program.methods
+        .authorizeMint(mintId, expiresAt, maxStakeSeconds)
+        .accounts({
+          stakePool: stakePoolId,
+          stakeAuthorizationRecord: findStakeAuthorizationRecordId(
+            stakePoolId,
+            mintId
+          ),
+          authority: provider.wallet.publicKey,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+  }
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const stakeAuthorizationRecord = await fetchIdlAccount(
+    provider.connection,
+    findStakeAuthorizationRecordId(stakePoolId, budgetMintId),
+    "stakeAuthorizationRecord"
+  );
+  expect(stakeAuthorizationRecord.parsed.expiresAt).toBeNull();
+  expect(stakeAuthorizationRecord.parsed.maxStakeSeconds?.toNumber()).toBe(
+    MAX_STAKE_SECONDS
+  );
+});
+
+test("Stake with expired authorization fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId: expiredMintId },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId: budgetMintId },
+      { mintId: revokedMintId },
+    ]),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, budgetMintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.stakeAuthorizationRecord?.toString()).toBe(
+    findStakeAuthorizationRecordId(stakePoolId, budgetMintId).toString()
+  );
+  expect(entry.parsed.authorizationMaxStakeSeconds?.toNumber()).toBe(
+    MAX_STAKE_SECONDS
+  );
+});
+
+test("Stake seconds capped by authorization budget", async () => {
+  await new Promise((r) => setTimeout(r, 4000));
+  const entry = await updateTotalStakeSeconds(budgetMintId);
+  expect(Number(entry.parsed.totalStakeSeconds)).toBe(MAX_STAKE_SECONDS);
+  expect(Number(entry.parsed.authorizationStakeSeconds)).toBe(
+    MAX_STAKE_SECONDS
+  );
+});
+
+test("Deauthorize stops accrual", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeAuthorizationRecordId = findStakeAuthorizationRecordId(
+    stakePoolId,
+    revokedMintId
+  );
+  const ix = await program.methods
+    .deauthorizeMint()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeAuthorizationRecord: stakeAuthorizationRecordId,
+      stakeEntry: findStakeEntryId(stakePoolId, revokedMintId),
+      authority: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  expect(
+    await provider.connection.getAccountInfo(stakeAuthorizationRecordId)
+  ).toBeNull();
+
+  const entryBefore = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, revokedMintId),
+    "stakeEntry"
+  );
+  expect(entryBefore.parsed.authorizationExpiresAt).not.toBeNull();
+  await new Promise((r) => setTimeout(r, 2000));
+  const entry = await updateTotalStakeSeconds(revokedMintId);
+  expect(entry.parsed.totalStakeSeconds.toString()).toBe(
+    entryBefore.parsed.totalStakeSeconds.toString()
+  );
+});
@@ -0,0 +1,275 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakeDenyRecordId,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.deniedMints).toBe(0);
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+});
+
+test("Deny staked mint", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .denyMint(mintId)
+    .accounts({
+      stakePool: stakePoolId,
+      stakeDenyRecord: findStakeDenyRecordId(stakePoolId, mintId),
+      stakeEntry: stakeEntryId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.deniedMints).toBe(1);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.denied).toBe(true);
+});
+
+test("Denied entry does not accrue", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const entryBefore = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  await new Promise((r) => setTimeout(r, 2000));
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.totalStakeSeconds.toString()).toBe(
+    entryBefore.parsed.totalStakeSeconds.toString()
+  );
+});
+
+test("Restake denied mint fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Undeny mint", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const stakeDenyRecordId = findStakeDenyRecordId(stakePoolId, mintId);
+  const ix = await program.methods
+    .undenyMint()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeDenyRecord: stakeDenyRecordId,
+      stakeEntry: stakeEntryId,
+      authority: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.deniedMints).toBe(0);
+  expect(
+    await provider.connection.getAccountInfo(stakeDenyRecordId)
+  ).toBeNull();
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.denied).toBe(false);
+});
+
+test("Sync stake entry denied", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .syncStakeEntryDenied()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      stakeDenyRecord: findStakeDenyRecordId(stakePoolId, mintId),
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.denied).toBe(false);
+});
+
+test("Restake after undeny", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.lastStaker.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
@@ -0,0 +1,329 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import {
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Ed25519Program,
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  findUserEscrowId,
+  findUserStakeRegistryId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  unstake,
+  withRemainingAccountsForPaymentInfo,
+} from "../../sdk";
+import {
+  findMintEditionId,
+  findMintMetadataId,
+  METADATA_PROGRAM_ID,
+} from "../../sdk/utils";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+const permitSigner = Keypair.generate();
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const stakeWithPermitTx = async (
+  expiresAt: number,
+  nonce: number,
+  signer = permitSigner,
+  otherPermitMintId?: PublicKey
+) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const tx = new Transaction();
+
+  // borsh encoded StakePermit signed by the pool's permit signer
+  const expiresAtBytes = Buffer.alloc(8);
+  expiresAtBytes.writeBigInt64LE(BigInt(expiresAt));
+  const nonceBytes = Buffer.alloc(8);
+  nonceBytes.writeBigUInt64LE(BigInt(nonce));
+  for (const permitMintId of [otherPermitMintId, mintId]) {
+    if (!permitMintId) continue;
+    tx.add(
+      Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: signer.secretKey,
+        message: Buffer.concat([
+          stakePoolId.toBuffer(),
+          permitMintId.toBuffer(),
+          provider.wallet.publicKey.toBuffer(),
+          expiresAtBytes,
+          nonceBytes,
+        ]),
+      })
+    );
+  }
+  const permitAccounts = [
+    { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+  ];
+
+  if (!(await provider.connection.getAccountInfo(stakeEntryId))) {
+    tx.add(
+      await program.methods
+        .initEntry(provider.wallet.publicKey, [], null)
+        .accounts({
+          stakeEntry: stakeEntryId,
+          stakePool: stakePoolId,
+          stakeMint: mintId,
+          stakeMintMetadata: findMintMetadataId(mintId),
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(permitAccounts)
+        .instruction()
+    );
+  }
+  const paymentAccounts = await withRemainingAccountsForPaymentInfo(
+    provider.connection,
+    tx,
+    provider.wallet.publicKey,
+    SOL_PAYMENT_INFO
+  );
+  tx.add(
+    await program.methods
+      .stakeEdition(new BN(1), [], null, null)
+      .accounts({
+        stakePool: stakePoolId,
+        stakeEntry: stakeEntryId,
+        stakeMint: mintId,
+        stakeMintEdition: findMintEditionId(mintId),
+        stakeMintMetadata: findMintMetadataId(mintId),
+        user: provider.wallet.publicKey,
+        userEscrow: findUserEscrowId(provider.wallet.publicKey),
+        userStakeRegistry: findUserStakeRegistryId(
+          stakePoolId,
+          provider.wallet.publicKey
+        ),
+        userStakeMintTokenAccount: getAssociatedTokenAddressSync(
+          mintId,
+          provider.wallet.publicKey
+        ),
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([...permitAccounts, ...paymentAccounts])
+      .instruction()
+  );
+  return tx;
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: true,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: permitSigner.publicKey,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.permitSigner?.toString()).toBe(
+    permitSigner.publicKey.toString()
+  );
+});
+
+test("Stake with permit from wrong signer fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await stakeWithPermitTx(
+        Math.floor(Date.now() / 1000) + 600,
+        1,
+        Keypair.generate()
+      ),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with expired permit fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await stakeWithPermitTx(Math.floor(Date.now() / 1000) - 600, 1),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with permit", async () => {
+  await executeTransaction(
+    provider.connection,
+    await stakeWithPermitTx(Math.floor(Date.now() / 1000) + 600, 1),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.lastStaker.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+  const userStakeRegistry = await fetchIdlAccount(
+    provider.connection,
+    findUserStakeRegistryId(stakePoolId, provider.wallet.publicKey),
+    "userStakeRegistry"
+  );
+  expect(Number(userStakeRegistry.parsed.permitNonce)).toBe(1);
+  const userAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(mintId, provider.wallet.publicKey)
+  );
+  expect(userAta.isFrozen).toBe(true);
+});
+
+test("Restake with used permit nonce fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await stakeWithPermitTx(Math.floor(Date.now() / 1000) + 600, 1),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Restake with new permit after another mint's permit", async () => {
+  await executeTransaction(
+    provider.connection,
+    await stakeWithPermitTx(
+      Math.floor(Date.now() / 1000) + 600,
+      2,
+      permitSigner,
+      Keypair.generate().publicKey
+    ),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const userStakeRegistry = await fetchIdlAccount(
+    provider.connection,
+    findUserStakeRegistryId(stakePoolId, provider.wallet.publicKey),
+    "userStakeRegistry"
+  );
+  expect(Number(userStakeRegistry.parsed.permitNonce)).toBe(2);
+});
+
+test("Restake closed entry with used permit nonce fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .closeStakeEntry()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      rentPayer: provider.wallet.publicKey,
+      authority: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  expect(await provider.connection.getAccountInfo(stakeEntryId)).toBeNull();
+
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await stakeWithPermitTx(Math.floor(Date.now() / 1000) + 600, 2),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
@@ -61,6 +61,23 @@ test("Init pool", async () => {
       endDate: null,
       stakePaymentInfo: SOL_PAYMENT_INFO,
       unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
     })
     .accounts({
       stakePool: stakePoolId,
@@ -112,7 +129,7 @@ test("Authorize mint", async () => {
   const transaction = new Transaction();
   transaction.add(
     await program.methods
-      .authorizeMint(mintId)
+      .authorizeMint(mintId, null, null)
       .accounts({
         stakePool: stakePoolId,
         stakeAuthorizationRecord: stakeAuthorizationId,
@@ -0,0 +1,283 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findLeaderboardId,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintIds: PublicKey[];
+const LEADERBOARD_SIZE = 2;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypairs = [...Array(2)].map(() => Keypair.generate());
+  mintIds = mintKeypairs.map((k) => k.publicKey);
+  for (const mintKeypair of mintKeypairs) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+});
+
+const initLeaderboardTx = async (size: number) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initLeaderboard({
+      identifier: new BN(0),
+      kind: 0,
+      rewardDistributor: null,
+      size,
+    })
+    .accounts({
+      leaderboard: findLeaderboardId(stakePoolId),
+      stakePool: stakePoolId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Init leaderboard with invalid size fail", async () => {
+  for (const size of [0, 200]) {
+    await expect(
+      executeTransaction(
+        provider.connection,
+        await initLeaderboardTx(size),
+        provider.wallet,
+        { silent: true }
+      )
+    ).rejects.toThrow();
+  }
+});
+
+test("Init leaderboard", async () => {
+  await executeTransaction(
+    provider.connection,
+    await initLeaderboardTx(LEADERBOARD_SIZE),
+    provider.wallet
+  );
+  const leaderboard = await fetchIdlAccount(
+    provider.connection,
+    findLeaderboardId(findStakePoolId(stakePoolIdentifier)),
+    "leaderboard"
+  );
+  expect(leaderboard.parsed.size).toBe(LEADERBOARD_SIZE);
+  expect(leaderboard.parsed.entries.length).toBe(0);
+});
+
+test("Stake and rank entries", async () => {
+  for (const mintId of mintIds) {
+    await executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId },
+      ]),
+      provider.wallet
+    );
+    await new Promise((r) => setTimeout(r, 2000));
+  }
+
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const leaderboardId = findLeaderboardId(stakePoolId);
+  const tx = new Transaction();
+  for (const mintId of [...mintIds].reverse()) {
+    tx.add(
+      await program.methods
+        .updateTotalStakeSeconds()
+        .accounts({
+          stakePool: stakePoolId,
+          stakeEntry: findStakeEntryId(stakePoolId, mintId),
+          updater: provider.wallet.publicKey,
+          leaderboard: leaderboardId,
+        })
+        .instruction()
+    );
+  }
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const leaderboard = await fetchIdlAccount(
+    provider.connection,
+    leaderboardId,
+    "leaderboard"
+  );
+  // the first mint has been staked the longest
+  expect(
+    leaderboard.parsed.entries.map((e) => e.stakeEntry.toString())
+  ).toEqual(
+    mintIds.map((mintId) => findStakeEntryId(stakePoolId, mintId).toString())
+  );
+  expect(Number(leaderboard.parsed.entries[0]!.value)).toBeGreaterThan(
+    Number(leaderboard.parsed.entries[1]!.value)
+  );
+});
+
+test("Update leaderboard with entry from previous season fail", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const leaderboardId = findLeaderboardId(stakePoolId);
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      await program.methods
+        .advanceSeason()
+        .accounts({
+          stakePool: stakePoolId,
+          authority: provider.wallet.publicKey,
+        })
+        .instruction()
+    ),
+    provider.wallet
+  );
+
+  const ix = await program.methods
+    .updateLeaderboard()
+    .accounts({
+      leaderboard: leaderboardId,
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintIds[0]!),
+      rewardEntry: null,
+    })
+    .instruction();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(ix),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Leaderboard restarts with the season", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const leaderboardId = findLeaderboardId(stakePoolId);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintIds[1]!);
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+      leaderboard: leaderboardId,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+
+  const leaderboard = await fetchIdlAccount(
+    provider.connection,
+    leaderboardId,
+    "leaderboard"
+  );
+  expect(leaderboard.parsed.season).toBe(1);
+  // the first mint has not been rolled into the new season yet
+  expect(
+    leaderboard.parsed.entries.map((e) => e.stakeEntry.toString())
+  ).toEqual([stakeEntryId.toString()]);
+});
+
+test("Close leaderboard", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const leaderboardId = findLeaderboardId(stakePoolId);
+  const ix = await program.methods
+    .closeLeaderboard()
+    .accounts({
+      leaderboard: leaderboardId,
+      stakePool: stakePoolId,
+      authority: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  expect(await provider.connection.getAccountInfo(leaderboardId)).toBeNull();
+});
@@ -81,6 +81,23 @@ test("Init pool", async () => {
       endDate: null,
       stakePaymentInfo: SOL_PAYMENT_INFO,
       unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
     })
     .accounts({
       stakePool: stakePoolId,
@@ -119,6 +136,7 @@ test("Init reward distributor", async () => {
       multiplierDecimals: 0,
       maxRewardSecondsReceived: null,
       claimRewardsPaymentInfo: DEFAULT_PAYMENT_INFO,
+      multiplierRoot: null,
     })
     .accounts({
       rewardDistributor: rewardDistributorId,
@@ -101,6 +101,23 @@ test("Init pool", async () => {
       endDate: null,
       stakePaymentInfo: SOL_PAYMENT_INFO,
       unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
     })
     .accounts({
       stakePool: stakePoolId,
@@ -139,6 +156,7 @@ test("Init reward distributor", async () => {
       multiplierDecimals: 0,
       maxRewardSecondsReceived: null,
       claimRewardsPaymentInfo: WRAPPED_SOL_PAYMENT_INFO,
+      multiplierRoot: null,
     })
     .accounts({
       rewardDistributor: rewardDistributorId,
@@ -0,0 +1,210 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMintTx,
+  executeTransaction,
+  newAccountWithLamports,
+} from "@solana-nft-programs/common";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findRewardBonusRuleId,
+  findRewardDistributorId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  WRAPPED_SOL_PAYMENT_INFO,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let rewardMintId: PublicKey;
+let nonAuthority: Keypair;
+const collectionId = Keypair.generate().publicKey;
+const BONUS_MULTIPLIER = 2;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  nonAuthority = await newAccountWithLamports(provider.connection);
+  const rewardMintKeypair = Keypair.generate();
+  rewardMintId = rewardMintKeypair.publicKey;
+  const [rewardMintTx] = await createMintTx(
+    provider.connection,
+    rewardMintId,
+    provider.wallet.publicKey
+  );
+  await executeTransaction(provider.connection, rewardMintTx, provider.wallet, {
+    signers: [rewardMintKeypair],
+  });
+});
+
+const initRewardBonusRuleTx = async (authority: Wallet) => {
+  const program = rewardsCenterProgram(provider.connection, authority);
+  const rewardDistributorId = findRewardDistributorId(
+    findStakePoolId(stakePoolIdentifier)
+  );
+  const ix = await program.methods
+    .initRewardBonusRule({
+      collection: collectionId,
+      multiplier: new BN(BONUS_MULTIPLIER),
+    })
+    .accounts({
+      rewardBonusRule: findRewardBonusRuleId(rewardDistributorId, collectionId),
+      rewardDistributor: rewardDistributorId,
+      authority: authority.publicKey,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Init reward distributor", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const ix = await program.methods
+    .initRewardDistributor({
+      identifier: new BN(0),
+      rewardAmount: new BN(1),
+      rewardDurationSeconds: new BN(1),
+      supply: null,
+      defaultMultiplier: new BN(1),
+      multiplierDecimals: 0,
+      maxRewardSecondsReceived: null,
+      claimRewardsPaymentInfo: WRAPPED_SOL_PAYMENT_INFO,
+      multiplierRoot: null,
+    })
+    .accounts({
+      rewardDistributor: rewardDistributorId,
+      stakePool: stakePoolId,
+      rewardMint: rewardMintId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+});
+
+test("Init bonus rule as non authority fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await initRewardBonusRuleTx(new Wallet(nonAuthority)),
+      new Wallet(nonAuthority),
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Init bonus rule", async () => {
+  await executeTransaction(
+    provider.connection,
+    await initRewardBonusRuleTx(provider.wallet),
+    provider.wallet
+  );
+  const rewardDistributorId = findRewardDistributorId(
+    findStakePoolId(stakePoolIdentifier)
+  );
+  const rewardBonusRule = await fetchIdlAccount(
+    provider.connection,
+    findRewardBonusRuleId(rewardDistributorId, collectionId),
+    "rewardBonusRule"
+  );
+  expect(rewardBonusRule.parsed.rewardDistributor.toString()).toBe(
+    rewardDistributorId.toString()
+  );
+  expect(rewardBonusRule.parsed.collection.toString()).toBe(
+    collectionId.toString()
+  );
+  expect(Number(rewardBonusRule.parsed.multiplier)).toBe(BONUS_MULTIPLIER);
+});
+
+test("Close bonus rule", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const rewardDistributorId = findRewardDistributorId(
+    findStakePoolId(stakePoolIdentifier)
+  );
+  const rewardBonusRuleId = findRewardBonusRuleId(
+    rewardDistributorId,
+    collectionId
+  );
+  const ix = await program.methods
+    .closeRewardBonusRule()
+    .accounts({
+      rewardDistributor: rewardDistributorId,
+      rewardBonusRule: rewardBonusRuleId,
+      authority: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  expect(
+    await provider.connection.getAccountInfo(rewardBonusRuleId)
+  ).toBeNull();
+});
@@ -0,0 +1,241 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMintTx,
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findRewardDistributorId,
+  findRewardEntryId,
+  findStakeEntryId,
+  findStakePoolId,
+  merkleProof,
+  merkleRoot,
+  multiplierLeaf,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  WRAPPED_SOL_PAYMENT_INFO,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+let rewardMintId: PublicKey;
+const MULTIPLIERS = [5, 3];
+let multiplierLeaves: Buffer[];
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  const rewardMintKeypair = Keypair.generate();
+  rewardMintId = rewardMintKeypair.publicKey;
+  const [rewardMintTx] = await createMintTx(
+    provider.connection,
+    rewardMintId,
+    provider.wallet.publicKey
+  );
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(
+      ...(
+        await createMasterEditionTx(
+          provider.connection,
+          mintKeypair.publicKey,
+          provider.wallet.publicKey
+        )
+      ).instructions,
+      ...rewardMintTx.instructions
+    ),
+    provider.wallet,
+    { signers: [mintKeypair, rewardMintKeypair] }
+  );
+  multiplierLeaves = [
+    multiplierLeaf(mintId, MULTIPLIERS[0]!),
+    multiplierLeaf(Keypair.generate().publicKey, MULTIPLIERS[1]!),
+  ];
+});
+
+const syncRewardEntryMultiplierTx = async (
+  multiplier: number,
+  proof: number[][]
+) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .syncRewardEntryMultiplier({ multiplier: new BN(multiplier), proof })
+    .accounts({
+      rewardEntry: findRewardEntryId(rewardDistributorId, stakeEntryId),
+      rewardDistributor: rewardDistributorId,
+      stakeEntry: stakeEntryId,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Init reward distributor", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const ix = await program.methods
+    .initRewardDistributor({
+      identifier: new BN(0),
+      rewardAmount: new BN(1),
+      rewardDurationSeconds: new BN(1),
+      supply: null,
+      defaultMultiplier: new BN(1),
+      multiplierDecimals: 0,
+      maxRewardSecondsReceived: null,
+      claimRewardsPaymentInfo: WRAPPED_SOL_PAYMENT_INFO,
+      multiplierRoot: merkleRoot(multiplierLeaves),
+    })
+    .accounts({
+      rewardDistributor: rewardDistributorId,
+      stakePool: stakePoolId,
+      rewardMint: rewardMintId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const rewardDistributor = await fetchIdlAccount(
+    provider.connection,
+    rewardDistributorId,
+    "rewardDistributor"
+  );
+  expect(rewardDistributor.parsed.multiplierRoot).toEqual(
+    merkleRoot(multiplierLeaves)
+  );
+});
+
+test("Stake and init reward entry", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .initRewardEntry()
+    .accounts({
+      rewardEntry: findRewardEntryId(rewardDistributorId, stakeEntryId),
+      rewardDistributor: rewardDistributorId,
+      stakeEntry: stakeEntryId,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+});
+
+test("Sync multiplier with wrong value fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await syncRewardEntryMultiplierTx(
+        MULTIPLIERS[1]!,
+        merkleProof(multiplierLeaves, 0)
+      ),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Sync multiplier", async () => {
+  await executeTransaction(
+    provider.connection,
+    await syncRewardEntryMultiplierTx(
+      MULTIPLIERS[0]!,
+      merkleProof(multiplierLeaves, 0)
+    ),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const rewardEntry = await fetchIdlAccount(
+    provider.connection,
+    findRewardEntryId(
+      rewardDistributorId,
+      findStakeEntryId(stakePoolId, mintId)
+    ),
+    "rewardEntry"
+  );
+  expect(Number(rewardEntry.parsed.multiplier)).toBe(MULTIPLIERS[0]);
+});
@@ -0,0 +1,250 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMintTx,
+  executeTransaction,
+  executeTransactions,
+  withFindOrInitAssociatedTokenAccount,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  claimRewardReceipt,
+  fetchIdlAccount,
+  findReceiptManagerId,
+  findRewardDistributorId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+const RECEIPT_MANAGER_IDENTIFIER = "receipt-manager-1";
+const STARTING_AMOUNT = 100;
+const PAYMENT_AMOUNT = 10;
+let mintId: PublicKey;
+let rewardMintId: PublicKey;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  const mintTx = await createMasterEditionTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey
+  );
+
+  const rewardMintKeypair = Keypair.generate();
+  rewardMintId = rewardMintKeypair.publicKey;
+  const [rewardMintTx] = await createMintTx(
+    provider.connection,
+    rewardMintId,
+    provider.wallet.publicKey,
+    { amount: STARTING_AMOUNT }
+  );
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(...mintTx.instructions, ...rewardMintTx.instructions),
+    provider.wallet,
+    { signers: [mintKeypair, rewardMintKeypair] }
+  );
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Init reward distributor", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const ix = await program.methods
+    .initRewardDistributor({
+      identifier: new BN(0),
+      rewardAmount: new BN(1),
+      rewardDurationSeconds: new BN(1),
+      supply: null,
+      defaultMultiplier: new BN(1),
+      multiplierDecimals: 0,
+      maxRewardSecondsReceived: null,
+      claimRewardsPaymentInfo: SOL_PAYMENT_INFO,
+      multiplierRoot: null,
+    })
+    .accounts({
+      rewardDistributor: rewardDistributorId,
+      stakePool: stakePoolId,
+      rewardMint: rewardMintId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  tx.add(ix);
+  await withFindOrInitAssociatedTokenAccount(
+    tx,
+    provider.connection,
+    rewardMintId,
+    rewardDistributorId,
+    provider.wallet.publicKey,
+    true
+  );
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const rewardDistributor = await fetchIdlAccount(
+    provider.connection,
+    rewardDistributorId,
+    "rewardDistributor"
+  );
+  expect(rewardDistributor.parsed.rewardMint.toString()).toBe(
+    rewardMintId.toString()
+  );
+});
+
+test("Create receipt manager paying the reward distributor", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const receiptManagerId = findReceiptManagerId(
+    stakePoolId,
+    RECEIPT_MANAGER_IDENTIFIER
+  );
+  const ix = await program.methods
+    .initReceiptManager({
+      name: RECEIPT_MANAGER_IDENTIFIER,
+      authority: provider.wallet.publicKey,
+      requiredStakeSeconds: new BN(0),
+      stakeSecondsToUse: new BN(0),
+      paymentMint: rewardMintId,
+      paymentAmount: new BN(PAYMENT_AMOUNT),
+      paymentShares: [
+        {
+          address: findRewardDistributorId(stakePoolId),
+          basisPoints: 10000,
+        },
+      ],
+      requiresAuthorization: false,
+      maxClaimedReceipts: null,
+      claimActionPaymentInfo: SOL_PAYMENT_INFO,
+    })
+    .accounts({
+      receiptManager: receiptManagerId,
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const receiptManager = await fetchIdlAccount(
+    provider.connection,
+    receiptManagerId,
+    "receiptManager"
+  );
+  expect(receiptManager.parsed.paymentMint.toString()).toBe(
+    rewardMintId.toString()
+  );
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+});
+
+test("Claim receipt funds treasury", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const receiptManagerId = findReceiptManagerId(
+    stakePoolId,
+    RECEIPT_MANAGER_IDENTIFIER
+  );
+  await executeTransaction(
+    provider.connection,
+    await claimRewardReceipt(
+      provider.connection,
+      provider.wallet,
+      stakePoolIdentifier,
+      { mintId },
+      receiptManagerId
+    ),
+    provider.wallet
+  );
+
+  const rewardDistributorAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(rewardMintId, rewardDistributorId, true)
+  );
+  expect(Number(rewardDistributorAta.amount)).toBe(PAYMENT_AMOUNT);
+  const rewardDistributor = await fetchIdlAccount(
+    provider.connection,
+    rewardDistributorId,
+    "rewardDistributor"
+  );
+  expect(Number(rewardDistributor.parsed.rewardsFunded)).toBe(PAYMENT_AMOUNT);
+  const userRewardMintAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(rewardMintId, provider.wallet.publicKey)
+  );
+  expect(Number(userRewardMintAta.amount)).toBe(
+    STARTING_AMOUNT - PAYMENT_AMOUNT
+  );
+});
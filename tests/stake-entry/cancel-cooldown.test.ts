@@ -0,0 +1,178 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+  newAccountWithLamports,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+let nonStaker: Keypair;
+const COOLDOWN_SECONDS = 600;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  nonStaker = await newAccountWithLamports(provider.connection);
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const cancelCooldownTx = async (user: Wallet) => {
+  const program = rewardsCenterProgram(provider.connection, user);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .cancelCooldown()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintId),
+      user: user.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: COOLDOWN_SECONDS,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.cooldownSeconds).toBe(COOLDOWN_SECONDS);
+});
+
+test("Cancel cooldown when not in cooldown fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await cancelCooldownTx(provider.wallet),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Start cooldown", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(findStakePoolId(stakePoolIdentifier), mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.cooldownStartSeconds).not.toBeNull();
+});
+
+test("Cancel cooldown as non staker fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await cancelCooldownTx(new Wallet(nonStaker)),
+      new Wallet(nonStaker),
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Cancel cooldown", async () => {
+  await executeTransaction(
+    provider.connection,
+    await cancelCooldownTx(provider.wallet),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(findStakePoolId(stakePoolIdentifier), mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.cooldownStartSeconds).toBeNull();
+  expect(entry.parsed.lastStaker.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+  const userAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(mintId, provider.wallet.publicKey)
+  );
+  expect(userAta.isFrozen).toBe(true);
+});
@@ -0,0 +1,322 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMintTx,
+  executeTransaction,
+  executeTransactions,
+  newAccountWithLamports,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  DEFAULT_PAYMENT_INFO,
+  fetchIdlAccount,
+  findRewardDistributorId,
+  findRewardEntryId,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { findMintMetadataId } from "../../sdk/utils";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+let rentPayer: Keypair;
+let otherUser: Keypair;
+let rewardMintId: PublicKey;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  rentPayer = await newAccountWithLamports(provider.connection);
+  otherUser = await newAccountWithLamports(provider.connection);
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  const mintTx = await createMasterEditionTx(
+    provider.connection,
+    mintKeypair.publicKey,
+    provider.wallet.publicKey
+  );
+  const rewardMintKeypair = Keypair.generate();
+  rewardMintId = rewardMintKeypair.publicKey;
+  const [rewardMintTx] = await createMintTx(
+    provider.connection,
+    rewardMintId,
+    provider.wallet.publicKey,
+    { amount: 1 }
+  );
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(...mintTx.instructions, ...rewardMintTx.instructions),
+    provider.wallet,
+    { signers: [mintKeypair, rewardMintKeypair] }
+  );
+});
+
+const closeStakeEntryTx = async (authority: PublicKey) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .closeStakeEntry()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintId),
+      rentPayer: rentPayer.publicKey,
+      authority,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+const closeRewardEntryTx = async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const ix = await program.methods
+    .closeRewardEntry()
+    .accounts({
+      rewardDistributor: rewardDistributorId,
+      rewardEntry: findRewardEntryId(rewardDistributorId, stakeEntryId),
+      stakeEntry: stakeEntryId,
+      rentPayer: rentPayer.publicKey,
+      authority: rentPayer.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Init entry from another payer", async () => {
+  const program = rewardsCenterProgram(
+    provider.connection,
+    new Wallet(rentPayer)
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .initEntry(provider.wallet.publicKey, [], null)
+    .accounts({
+      stakeEntry: stakeEntryId,
+      stakePool: stakePoolId,
+      stakeMint: mintId,
+      stakeMintMetadata: findMintMetadataId(mintId),
+      payer: rentPayer.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    new Wallet(rentPayer)
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.rentPayer.toString()).toBe(
+    rentPayer.publicKey.toString()
+  );
+});
+
+test("Init reward distributor and entry from another payer", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const rewardDistributorId = findRewardDistributorId(stakePoolId);
+  const rewardEntryId = findRewardEntryId(rewardDistributorId, stakeEntryId);
+  const distributorIx = await program.methods
+    .initRewardDistributor({
+      identifier: new BN(0),
+      rewardAmount: new BN(1),
+      rewardDurationSeconds: new BN(1),
+      supply: null,
+      defaultMultiplier: new BN(1),
+      multiplierDecimals: 0,
+      maxRewardSecondsReceived: null,
+      claimRewardsPaymentInfo: DEFAULT_PAYMENT_INFO,
+      multiplierRoot: null,
+    })
+    .accounts({
+      rewardDistributor: rewardDistributorId,
+      stakePool: stakePoolId,
+      rewardMint: rewardMintId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  const rewardEntryIx = await program.methods
+    .initRewardEntry()
+    .accounts({
+      rewardEntry: rewardEntryId,
+      rewardDistributor: rewardDistributorId,
+      stakeEntry: stakeEntryId,
+      payer: rentPayer.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(distributorIx, rewardEntryIx),
+    provider.wallet,
+    { signers: [rentPayer] }
+  );
+  const rewardEntry = await fetchIdlAccount(
+    provider.connection,
+    rewardEntryId,
+    "rewardEntry"
+  );
+  expect(rewardEntry.parsed.rentPayer.toString()).toBe(
+    rentPayer.publicKey.toString()
+  );
+});
+
+test("Close staked entry fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await closeStakeEntryTx(provider.wallet.publicKey),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Close entry as other user fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await closeStakeEntryTx(otherUser.publicKey),
+      new Wallet(otherUser),
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Close reward entry before stake entry fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await closeRewardEntryTx(),
+      provider.wallet,
+      { silent: true, signers: [rentPayer] }
+    )
+  ).rejects.toThrow();
+});
+
+test("Close entry as rent payer", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const entryInfo = await provider.connection.getAccountInfo(stakeEntryId);
+  const rentPayerBalance = await provider.connection.getBalance(
+    rentPayer.publicKey
+  );
+  await executeTransaction(
+    provider.connection,
+    await closeStakeEntryTx(rentPayer.publicKey),
+    provider.wallet,
+    { signers: [rentPayer] }
+  );
+  expect(await provider.connection.getAccountInfo(stakeEntryId)).toBeNull();
+  expect(await provider.connection.getBalance(rentPayer.publicKey)).toBe(
+    rentPayerBalance + entryInfo!.lamports
+  );
+});
+
+test("Close reward entry as rent payer", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const rewardEntryId = findRewardEntryId(
+    findRewardDistributorId(stakePoolId),
+    findStakeEntryId(stakePoolId, mintId)
+  );
+  const rewardEntryInfo = await provider.connection.getAccountInfo(
+    rewardEntryId
+  );
+  const rentPayerBalance = await provider.connection.getBalance(
+    rentPayer.publicKey
+  );
+  await executeTransaction(
+    provider.connection,
+    await closeRewardEntryTx(),
+    provider.wallet,
+    { signers: [rentPayer] }
+  );
+  expect(await provider.connection.getAccountInfo(rewardEntryId)).toBeNull();
+  expect(await provider.connection.getBalance(rentPayer.publicKey)).toBe(
+    rentPayerBalance + rewardEntryInfo!.lamports
+  );
+});
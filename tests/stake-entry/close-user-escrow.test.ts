@@ -0,0 +1,157 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect, test } from "@jest/globals";
+import type { Keypair, PublicKey } from "@solana/web3.js";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+  newAccountWithLamports,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakePoolId,
+  findUserEscrowId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createProgrammableAsset } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+// escrows are per user, so use a fresh one that no other test stakes with
+let user: Keypair;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  user = await newAccountWithLamports(provider.connection);
+  [, mintId] = await createProgrammableAsset(
+    provider.connection,
+    new Wallet(user)
+  );
+});
+
+const closeUserEscrowTx = async () => {
+  const program = rewardsCenterProgram(provider.connection, new Wallet(user));
+  const ix = await program.methods
+    .closeUserEscrow()
+    .accounts({
+      userEscrow: findUserEscrowId(user.publicKey),
+      user: user.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, new Wallet(user), stakePoolIdentifier, [
+      { mintId },
+    ]),
+    new Wallet(user)
+  );
+  const userEscrow = await fetchIdlAccount(
+    provider.connection,
+    findUserEscrowId(user.publicKey),
+    "userEscrow"
+  );
+  expect(userEscrow.parsed.user.toString()).toBe(user.publicKey.toString());
+  expect(Number(userEscrow.parsed.delegations)).toBe(1);
+});
+
+test("Close escrow with delegations fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await closeUserEscrowTx(),
+      new Wallet(user),
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Unstake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, new Wallet(user), stakePoolIdentifier, [
+      { mintId },
+    ]),
+    new Wallet(user)
+  );
+  const userEscrow = await fetchIdlAccount(
+    provider.connection,
+    findUserEscrowId(user.publicKey),
+    "userEscrow"
+  );
+  expect(Number(userEscrow.parsed.delegations)).toBe(0);
+});
+
+test("Close escrow", async () => {
+  await executeTransaction(
+    provider.connection,
+    await closeUserEscrowTx(),
+    new Wallet(user)
+  );
+  expect(
+    await provider.connection.getAccountInfo(findUserEscrowId(user.publicKey))
+  ).toBeNull();
+});
@@ -0,0 +1,186 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../../sdk";
+import { getTestProvider } from "../../../tools/utils";
+import { createMasterEditionTx } from "../../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+const LOCK_SECONDS = 600;
+const LOCK_MULTIPLIER_BASIS_POINTS = 20000;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [
+        {
+          lockSeconds: LOCK_SECONDS,
+          multiplierBasisPoints: new BN(LOCK_MULTIPLIER_BASIS_POINTS),
+        },
+      ],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.lockTiers.length).toBe(1);
+  expect(pool.parsed.lockTiers[0]!.lockSeconds).toBe(LOCK_SECONDS);
+});
+
+test("Stake with unknown lock tier fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId, lockTier: 1 },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with lock tier", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId, lockTier: 0 },
+    ]),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.unlockAt?.toNumber()).toBeGreaterThan(
+    Date.now() / 1000
+  );
+  expect(entry.parsed.lockMultiplierBasisPoints?.toNumber()).toBe(
+    LOCK_MULTIPLIER_BASIS_POINTS
+  );
+});
+
+test("Locked stake seconds multiplied", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(Number(entry.parsed.totalStakeSeconds)).toBeGreaterThan(0);
+  expect(Number(entry.parsed.multiplierStakeSeconds)).toBe(
+    (Number(entry.parsed.totalStakeSeconds) * LOCK_MULTIPLIER_BASIS_POINTS) /
+      10000
+  );
+});
+
+test("Unstake before unlock fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
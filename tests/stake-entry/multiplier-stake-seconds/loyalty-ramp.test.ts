@@ -0,0 +1,184 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../../sdk";
+import { getTestProvider } from "../../../tools/utils";
+import { createMasterEditionTx } from "../../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const initPoolTx = async (ramp: {
+  intervalSeconds: number;
+  incrementBasisPoints: BN;
+  maxBonusBasisPoints: BN;
+}) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: ramp,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool with zero interval fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await initPoolTx({
+        intervalSeconds: 0,
+        incrementBasisPoints: new BN(1000),
+        maxBonusBasisPoints: new BN(10000),
+      }),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Init pool with too many ramp steps fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await initPoolTx({
+        intervalSeconds: 1,
+        incrementBasisPoints: new BN(1),
+        maxBonusBasisPoints: new BN(10000),
+      }),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Init pool", async () => {
+  await executeTransaction(
+    provider.connection,
+    await initPoolTx({
+      intervalSeconds: 1,
+      incrementBasisPoints: new BN(10000),
+      maxBonusBasisPoints: new BN(10000),
+    }),
+    provider.wallet
+  );
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    findStakePoolId(stakePoolIdentifier),
+    "stakePool"
+  );
+  expect(pool.parsed.loyaltyRamp?.intervalSeconds).toBe(1);
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.loyaltyRamp?.maxBonusBasisPoints.toNumber()).toBe(
+    10000
+  );
+});
+
+test("Stake seconds ramp up", async () => {
+  await new Promise((r) => setTimeout(r, 4000));
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  // the first interval accrues at the base rate, the rest at the capped bonus
+  const totalStakeSeconds = Number(entry.parsed.totalStakeSeconds);
+  expect(Number(entry.parsed.multiplierStakeSeconds)).toBe(
+    totalStakeSeconds * 2 - 1
+  );
+});
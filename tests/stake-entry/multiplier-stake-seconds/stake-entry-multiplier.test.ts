@@ -69,6 +69,23 @@ test("Init pool", async () => {
       endDate: null,
       stakePaymentInfo: SOL_PAYMENT_INFO,
       unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
     })
     .accounts({
       stakePool: stakePoolId,
@@ -94,7 +111,7 @@ test("Init entry", async () => {
   const stakePoolId = findStakePoolId(stakePoolIdentifier);
   const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
   const ix = await rewardsCenterProgram(provider.connection, provider.wallet)
-    .methods.initEntry(provider.wallet.publicKey)
+    .methods.initEntry(provider.wallet.publicKey, [], null)
     .accountsStrict({
       stakePool: stakePoolId,
       stakeEntry: stakeEntryId,
@@ -225,6 +242,7 @@ test("Update total stake seconds", async () => {
   const ix = await rewardsCenterProgram(provider.connection, provider.wallet)
     .methods.updateTotalStakeSeconds()
     .accounts({
+      stakePool: stakePoolId,
       stakeEntry: stakeEntryId,
       updater: provider.wallet.publicKey,
     })
@@ -0,0 +1,203 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+  newAccountWithLamports,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../../sdk";
+import { getTestProvider } from "../../../tools/utils";
+import { createMasterEditionTx } from "../../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+let nonAuthority: Keypair;
+const TIMED_MULTIPLIER_BASIS_POINTS = 20000;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  nonAuthority = await newAccountWithLamports(provider.connection);
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const addTimedMultiplierTx = async (expiresAt: number, authority: Wallet) => {
+  const program = rewardsCenterProgram(provider.connection, authority);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .addStakeEntryTimedMultiplier(
+      new BN(TIMED_MULTIPLIER_BASIS_POINTS),
+      new BN(expiresAt)
+    )
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintId),
+      authority: authority.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+});
+
+test("Add expired timed multiplier fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await addTimedMultiplierTx(
+        Math.floor(Date.now() / 1000) - 600,
+        provider.wallet
+      ),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Add timed multiplier as non authority fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await addTimedMultiplierTx(
+        Math.floor(Date.now() / 1000) + 600,
+        new Wallet(nonAuthority)
+      ),
+      new Wallet(nonAuthority),
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Timed multiplier applies from when it is added", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  await executeTransaction(
+    provider.connection,
+    await addTimedMultiplierTx(
+      Math.floor(Date.now() / 1000) + 600,
+      provider.wallet
+    ),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const entryBefore = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(
+    entryBefore.parsed.timedMultipliers.filter((t) => t !== null).length
+  ).toBe(1);
+
+  await new Promise((r) => setTimeout(r, 2000));
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  const secondsBefore = Number(entryBefore.parsed.totalStakeSeconds);
+  const secondsAfter = Number(entry.parsed.totalStakeSeconds);
+  expect(secondsAfter).toBeGreaterThan(secondsBefore);
+  expect(Number(entry.parsed.multiplierStakeSeconds)).toBe(
+    secondsBefore +
+      ((secondsAfter - secondsBefore) * TIMED_MULTIPLIER_BASIS_POINTS) / 10000
+  );
+});
@@ -0,0 +1,173 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+const DECAY_PERIOD_SECONDS = 600;
+let unstakedStakeSeconds: number;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const initPoolTx = async (stakeSecondsDecay: {
+  kind: number;
+  periodSeconds: number;
+}) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool with unknown decay kind fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await initPoolTx({ kind: 2, periodSeconds: DECAY_PERIOD_SECONDS }),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Init pool with zero decay period fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await initPoolTx({ kind: 1, periodSeconds: 0 }),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Init pool", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  await executeTransaction(
+    provider.connection,
+    await initPoolTx({ kind: 1, periodSeconds: DECAY_PERIOD_SECONDS }),
+    provider.wallet
+  );
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.stakeSecondsDecay?.periodSeconds).toBe(
+    DECAY_PERIOD_SECONDS
+  );
+});
+
+test("Stake and unstake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await new Promise((r) => setTimeout(r, 4000));
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(findStakePoolId(stakePoolIdentifier), mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.unstakedAt).not.toBeNull();
+  unstakedStakeSeconds = Number(entry.parsed.totalStakeSeconds);
+  expect(unstakedStakeSeconds).toBeGreaterThan(1);
+});
+
+test("Restake decays stake seconds", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(findStakePoolId(stakePoolIdentifier), mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.unstakedAt).toBeNull();
+  expect(Number(entry.parsed.totalStakeSeconds)).toBeLessThan(
+    unstakedStakeSeconds
+  );
+});
@@ -0,0 +1,245 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import type { Trait } from "../../sdk";
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  findStakeSetId,
+  merkleProof,
+  merkleRoot,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  traitsLeaf,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintIds: PublicKey[];
+const MINT_TRAITS: Trait[][] = [
+  [
+    { traitType: "background", value: "gold" },
+    { traitType: "eyes", value: "laser" },
+  ],
+  [
+    { traitType: "background", value: "silver" },
+    { traitType: "eyes", value: "laser" },
+  ],
+];
+const STAKE_SET_MULTIPLIER_BASIS_POINTS = 15000;
+let traitLeaves: Buffer[];
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypairs = [...Array(2)].map(() => Keypair.generate());
+  mintIds = mintKeypairs.map((k) => k.publicKey);
+  for (const mintKeypair of mintKeypairs) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+  traitLeaves = mintIds.map((mintId, i) => traitsLeaf(mintId, MINT_TRAITS[i]!));
+});
+
+const traitProof = (i: number) => ({
+  traits: MINT_TRAITS[i]!,
+  proof: merkleProof(traitLeaves, i),
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: merkleRoot(traitLeaves),
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [
+        {
+          collections: [],
+          traitMatch: { traitType: "eyes", value: "laser" },
+          size: 2,
+          multiplierBasisPoints: new BN(STAKE_SET_MULTIPLIER_BASIS_POINTS),
+        },
+      ],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.stakeSetRules.length).toBe(1);
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(
+      provider.connection,
+      provider.wallet,
+      stakePoolIdentifier,
+      mintIds.map((mintId, i) => ({ mintId, traitProof: traitProof(i) }))
+    ),
+    provider.wallet
+  );
+});
+
+test("Form stake set with too few members fail", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .formStakeSet({
+      identifier: new BN(0),
+      rule: 0,
+      traitProofs: [traitProof(0)],
+    })
+    .accounts({
+      stakeSet: findStakeSetId(stakePoolId, provider.wallet.publicKey),
+      stakePool: stakePoolId,
+      user: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts([
+      {
+        pubkey: findStakeEntryId(stakePoolId, mintIds[0]!),
+        isSigner: false,
+        isWritable: true,
+      },
+    ])
+    .instruction();
+  await expect(
+    executeTransaction(
+      provider.connection,
+      new Transaction().add(ix),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Form stake set", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeSetId = findStakeSetId(stakePoolId, provider.wallet.publicKey);
+  const ix = await program.methods
+    .formStakeSet({
+      identifier: new BN(0),
+      rule: 0,
+      traitProofs: mintIds.map((_, i) => traitProof(i)),
+    })
+    .accounts({
+      stakeSet: stakeSetId,
+      stakePool: stakePoolId,
+      user: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .remainingAccounts(
+      mintIds.map((mintId) => ({
+        pubkey: findStakeEntryId(stakePoolId, mintId),
+        isSigner: false,
+        isWritable: true,
+      }))
+    )
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+
+  const stakeSet = await fetchIdlAccount(
+    provider.connection,
+    stakeSetId,
+    "stakeSet"
+  );
+  expect(stakeSet.parsed.stakeEntries.length).toBe(2);
+  expect(stakeSet.parsed.multiplierBasisPoints.toNumber()).toBe(
+    STAKE_SET_MULTIPLIER_BASIS_POINTS
+  );
+  for (const mintId of mintIds) {
+    const entry = await fetchIdlAccount(
+      provider.connection,
+      findStakeEntryId(stakePoolId, mintId),
+      "stakeEntry"
+    );
+    expect(entry.parsed.stakeSet?.toString()).toBe(stakeSetId.toString());
+    expect(entry.parsed.stakeSetMultiplierBasisPoints?.toNumber()).toBe(
+      STAKE_SET_MULTIPLIER_BASIS_POINTS
+    );
+  }
+});
+
+test("Unstake member dissolves stake set", async () => {
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId: mintIds[0]! },
+    ]),
+    provider.wallet
+  );
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  expect(
+    await provider.connection.getAccountInfo(
+      findStakeSetId(stakePoolId, provider.wallet.publicKey)
+    )
+  ).toBeNull();
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintIds[1]!),
+    "stakeEntry"
+  );
+  expect(entry.parsed.stakeSet).toBeNull();
+  expect(entry.parsed.stakeSetMultiplierBasisPoints).toBeNull();
+});
@@ -0,0 +1,192 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  findUserStakeRegistryId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let fromMintId: PublicKey;
+let toMintId: PublicKey;
+const SWAP_STAKE_BASIS_POINTS = 5000;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypairs = [...Array(2)].map(() => Keypair.generate());
+  [fromMintId, toMintId] = mintKeypairs.map(
+    (k) => k.publicKey
+  ) as [PublicKey, PublicKey];
+  for (const mintKeypair of mintKeypairs) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+});
+
+const swapStakeTx = async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .swapStake()
+    .accounts({
+      stakePool: stakePoolId,
+      fromStakeEntry: findStakeEntryId(stakePoolId, fromMintId),
+      toStakeEntry: findStakeEntryId(stakePoolId, toMintId),
+      user: provider.wallet.publicKey,
+      userStakeRegistry: findUserStakeRegistryId(
+        stakePoolId,
+        provider.wallet.publicKey
+      ),
+      sysvarInstructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: new BN(SWAP_STAKE_BASIS_POINTS),
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.swapStakeBasisPoints?.toNumber()).toBe(
+    SWAP_STAKE_BASIS_POINTS
+  );
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId: fromMintId },
+      { mintId: toMintId },
+    ]),
+    provider.wallet
+  );
+  await new Promise((r) => setTimeout(r, 4000));
+});
+
+test("Swap without unstaking source fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await swapStakeTx(),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Swap and unstake source", async () => {
+  const tx = await swapStakeTx();
+  for (const unstakeTx of await unstake(
+    provider.connection,
+    provider.wallet,
+    stakePoolIdentifier,
+    [{ mintId: fromMintId }]
+  )) {
+    tx.add(...unstakeTx.instructions);
+  }
+  await executeTransaction(provider.connection, tx, provider.wallet);
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const fromEntry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, fromMintId),
+    "stakeEntry"
+  );
+  const toEntry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, toMintId),
+    "stakeEntry"
+  );
+  expect(fromEntry.parsed.lastStaker.toString()).toBe(
+    SystemProgram.programId.toString()
+  );
+  expect(Number(toEntry.parsed.swappedInStakeSeconds)).toBeGreaterThan(0);
+  expect(Number(toEntry.parsed.totalStakeSeconds)).toBeGreaterThan(
+    Number(fromEntry.parsed.totalStakeSeconds)
+  );
+  // swapped seconds are counted once in the registry
+  const userStakeRegistry = await fetchIdlAccount(
+    provider.connection,
+    findUserStakeRegistryId(stakePoolId, provider.wallet.publicKey),
+    "userStakeRegistry"
+  );
+  expect(Number(userStakeRegistry.parsed.totalStakeSeconds)).toBe(
+    Number(fromEntry.parsed.totalStakeSeconds) +
+      Number(toEntry.parsed.totalStakeSeconds)
+  );
+});
@@ -0,0 +1,184 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  findUserStakeRegistryId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintIds: PublicKey[];
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypairs = [...Array(2)].map(() => Keypair.generate());
+  mintIds = mintKeypairs.map((k) => k.publicKey);
+  for (const mintKeypair of mintKeypairs) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(
+      provider.connection,
+      provider.wallet,
+      stakePoolIdentifier,
+      mintIds.map((mintId) => ({ mintId }))
+    ),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const registry = await fetchIdlAccount(
+    provider.connection,
+    findUserStakeRegistryId(stakePoolId, provider.wallet.publicKey),
+    "userStakeRegistry"
+  );
+  expect(registry.parsed.pool.toString()).toBe(stakePoolId.toString());
+  expect(registry.parsed.user.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+  expect(Number(registry.parsed.totalStaked)).toBe(2);
+  expect(
+    registry.parsed.stakeEntries.map((e) => e.stakeEntry.toString()).sort()
+  ).toEqual(
+    mintIds
+      .map((mintId) => findStakeEntryId(stakePoolId, mintId).toString())
+      .sort()
+  );
+});
+
+test("Unstake", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId: mintIds[0]! },
+    ]),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const registry = await fetchIdlAccount(
+    provider.connection,
+    findUserStakeRegistryId(stakePoolId, provider.wallet.publicKey),
+    "userStakeRegistry"
+  );
+  expect(Number(registry.parsed.totalStaked)).toBe(1);
+  expect(registry.parsed.stakeEntries.length).toBe(1);
+  expect(registry.parsed.stakeEntries[0]!.stakeEntry.toString()).toBe(
+    findStakeEntryId(stakePoolId, mintIds[1]!).toString()
+  );
+  expect(Number(registry.parsed.totalStakeSeconds)).toBeGreaterThan(1);
+});
+
+test("Backfill tracked entry is a no-op", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const userStakeRegistryId = findUserStakeRegistryId(
+    stakePoolId,
+    provider.wallet.publicKey
+  );
+  const ix = await program.methods
+    .backfillUserStakeRegistry()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintIds[1]!),
+      userStakeRegistry: userStakeRegistryId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+
+  const registry = await fetchIdlAccount(
+    provider.connection,
+    userStakeRegistryId,
+    "userStakeRegistry"
+  );
+  expect(Number(registry.parsed.totalStaked)).toBe(1);
+  expect(registry.parsed.stakeEntries.length).toBe(1);
+});
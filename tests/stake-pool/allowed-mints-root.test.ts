@@ -0,0 +1,156 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  merkleProof,
+  merkleRoot,
+  mintLeaf,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintIds: PublicKey[];
+let outsideMintId: PublicKey;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypairs = [...Array(4)].map(() => Keypair.generate());
+  mintIds = mintKeypairs.slice(0, 3).map((k) => k.publicKey);
+  outsideMintId = mintKeypairs[3]!.publicKey;
+  for (const mintKeypair of mintKeypairs) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: merkleRoot(mintIds.map((id) => mintLeaf(id))),
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.allowedMintsRoot).toEqual(
+    merkleRoot(mintIds.map((id) => mintLeaf(id)))
+  );
+});
+
+test("Stake mint outside root fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        {
+          mintId: outsideMintId,
+          mintProof: merkleProof(
+            mintIds.map((id) => mintLeaf(id)),
+            0
+          ),
+        },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake mint in root", async () => {
+  const mintId = mintIds[2]!;
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      {
+        mintId,
+        mintProof: merkleProof(
+          mintIds.map((id) => mintLeaf(id)),
+          2
+        ),
+      },
+    ]),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.stakeMint.toString()).toBe(mintId.toString());
+  expect(entry.parsed.lastStaker.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+  const userAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(mintId, provider.wallet.publicKey)
+  );
+  expect(userAta.isFrozen).toBe(true);
+});
@@ -0,0 +1,264 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+const BOOST_MULTIPLIER_BASIS_POINTS = 30000;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const now = Math.floor(Date.now() / 1000);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [
+        {
+          start: new BN(now - 600),
+          end: new BN(now + 600),
+          multiplierBasisPoints: new BN(BOOST_MULTIPLIER_BASIS_POINTS),
+        },
+      ],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.boostWindows.length).toBe(1);
+  expect(pool.parsed.boostWindows[0]!.multiplierBasisPoints.toNumber()).toBe(
+    BOOST_MULTIPLIER_BASIS_POINTS
+  );
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+});
+
+test("Stake seconds boosted inside window", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(Number(entry.parsed.totalStakeSeconds)).toBeGreaterThan(0);
+  expect(Number(entry.parsed.multiplierStakeSeconds)).toBe(
+    (Number(entry.parsed.totalStakeSeconds) * BOOST_MULTIPLIER_BASIS_POINTS) /
+      10000
+  );
+});
+
+const updatePoolTx = async (
+  boostWindows: { start: BN; end: BN; multiplierBasisPoints: BN }[]
+) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .updatePool({
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows,
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      authority: provider.wallet.publicKey,
+      payer: provider.wallet.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Add window starting in the past fail", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  const now = Math.floor(Date.now() / 1000);
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await updatePoolTx([
+        ...pool.parsed.boostWindows,
+        {
+          start: new BN(now - 600),
+          end: new BN(now + 1200),
+          multiplierBasisPoints: new BN(BOOST_MULTIPLIER_BASIS_POINTS),
+        },
+      ]),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Add window ending before it starts fail", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  const now = Math.floor(Date.now() / 1000);
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await updatePoolTx([
+        ...pool.parsed.boostWindows,
+        {
+          start: new BN(now + 1200),
+          end: new BN(now + 600),
+          multiplierBasisPoints: new BN(BOOST_MULTIPLIER_BASIS_POINTS),
+        },
+      ]),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Add future window keeping the current one", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  const now = Math.floor(Date.now() / 1000);
+  await executeTransaction(
+    provider.connection,
+    await updatePoolTx([
+      ...pool.parsed.boostWindows,
+      {
+        start: new BN(now + 1200),
+        end: new BN(now + 1800),
+        multiplierBasisPoints: new BN(BOOST_MULTIPLIER_BASIS_POINTS),
+      },
+    ]),
+    provider.wallet
+  );
+  const updatedPool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(updatedPool.parsed.boostWindows.length).toBe(2);
+});
@@ -0,0 +1,232 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  createMintTx,
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+
+import type { PaymentShare } from "../../sdk";
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  unstake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+let passMintKeypair: Keypair;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+  passMintKeypair = Keypair.generate();
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: passMintKeypair.publicKey,
+      feeExemptMint: passMintKeypair.publicKey,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.passMint?.toString()).toBe(
+    passMintKeypair.publicKey.toString()
+  );
+  expect(pool.parsed.feeExemptMint?.toString()).toBe(
+    passMintKeypair.publicKey.toString()
+  );
+});
+
+test("Stake without pass fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with token outside pass collection fail", async () => {
+  const otherMintKeypair = Keypair.generate();
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      otherMintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [otherMintKeypair] }
+  );
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        {
+          mintId,
+          passTokenAccountId: getAssociatedTokenAddressSync(
+            otherMintKeypair.publicKey,
+            provider.wallet.publicKey
+          ),
+        },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with pass is fee exempt", async () => {
+  const [passMintTx] = await createMintTx(
+    provider.connection,
+    passMintKeypair.publicKey,
+    provider.wallet.publicKey,
+    { amount: 1 }
+  );
+  await executeTransaction(provider.connection, passMintTx, provider.wallet, {
+    signers: [passMintKeypair],
+  });
+  const paymentInfo = await fetchIdlAccount(
+    provider.connection,
+    SOL_PAYMENT_INFO,
+    "paymentInfo"
+  );
+  const paymentTargetId = (
+    paymentInfo.parsed.paymentShares as PaymentShare[]
+  )[0]!.address;
+  const paymentTargetBalanceBefore = await provider.connection.getBalance(
+    paymentTargetId
+  );
+
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, mintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.lastStaker.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+  const userAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(mintId, provider.wallet.publicKey)
+  );
+  expect(userAta.isFrozen).toBe(true);
+  expect(await provider.connection.getBalance(paymentTargetId)).toBe(
+    paymentTargetBalanceBefore
+  );
+});
+
+test("Unstake with pass is fee exempt", async () => {
+  const paymentInfo = await fetchIdlAccount(
+    provider.connection,
+    SOL_PAYMENT_INFO,
+    "paymentInfo"
+  );
+  const paymentTargetId = (
+    paymentInfo.parsed.paymentShares as PaymentShare[]
+  )[0]!.address;
+  const paymentTargetBalanceBefore = await provider.connection.getBalance(
+    paymentTargetId
+  );
+
+  await executeTransactions(
+    provider.connection,
+    await unstake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+
+  const userAta = await getAccount(
+    provider.connection,
+    getAssociatedTokenAddressSync(mintId, provider.wallet.publicKey)
+  );
+  expect(userAta.isFrozen).toBe(false);
+  expect(await provider.connection.getBalance(paymentTargetId)).toBe(
+    paymentTargetBalanceBefore
+  );
+});
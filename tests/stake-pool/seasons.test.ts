@@ -0,0 +1,181 @@
+import { Wallet } from "@coral-xyz/anchor";
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+  newAccountWithLamports,
+} from "@solana-nft-programs/common";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+let nonAuthority: Keypair;
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  nonAuthority = await newAccountWithLamports(provider.connection);
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const advanceSeasonTx = async (authority: Wallet) => {
+  const program = rewardsCenterProgram(provider.connection, authority);
+  const ix = await program.methods
+    .advanceSeason()
+    .accounts({
+      stakePool: findStakePoolId(stakePoolIdentifier),
+      authority: authority.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.season).toBe(0);
+});
+
+test("Stake", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await new Promise((r) => setTimeout(r, 2000));
+});
+
+test("Advance season as non authority fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await advanceSeasonTx(new Wallet(nonAuthority)),
+      new Wallet(nonAuthority),
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Advance season", async () => {
+  await executeTransaction(
+    provider.connection,
+    await advanceSeasonTx(provider.wallet),
+    provider.wallet
+  );
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    findStakePoolId(stakePoolIdentifier),
+    "stakePool"
+  );
+  expect(pool.parsed.season).toBe(1);
+  expect(pool.parsed.seasonStartedAt.toNumber()).toBeGreaterThan(
+    Date.now() / 1000 - 60
+  );
+});
+
+test("Entry rolls into new season", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const entryBefore = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entryBefore.parsed.season).toBe(0);
+
+  const ix = await program.methods
+    .updateTotalStakeSeconds()
+    .accounts({
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      updater: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.season).toBe(1);
+  expect(Number(entry.parsed.previousSeasonStakeSeconds)).toBeGreaterThan(1);
+  expect(Number(entry.parsed.totalStakeSeconds)).toBeLessThan(
+    Number(entry.parsed.previousSeasonStakeSeconds)
+  );
+});
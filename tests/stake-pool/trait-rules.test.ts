@@ -0,0 +1,210 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+
+import type { Trait } from "../../sdk";
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  merkleProof,
+  merkleRoot,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+  traitsLeaf,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let goldMintId: PublicKey;
+let silverMintId: PublicKey;
+const GOLD_TRAITS: Trait[] = [
+  { traitType: "background", value: "gold" },
+  { traitType: "eyes", value: "laser" },
+];
+const SILVER_TRAITS: Trait[] = [
+  { traitType: "background", value: "silver" },
+  { traitType: "eyes", value: "laser" },
+];
+let traitLeaves: Buffer[];
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const goldMintKeypair = Keypair.generate();
+  const silverMintKeypair = Keypair.generate();
+  goldMintId = goldMintKeypair.publicKey;
+  silverMintId = silverMintKeypair.publicKey;
+  for (const mintKeypair of [goldMintKeypair, silverMintKeypair]) {
+    await executeTransaction(
+      provider.connection,
+      await createMasterEditionTx(
+        provider.connection,
+        mintKeypair.publicKey,
+        provider.wallet.publicKey
+      ),
+      provider.wallet,
+      { signers: [mintKeypair] }
+    );
+  }
+  traitLeaves = [
+    traitsLeaf(goldMintId, GOLD_TRAITS),
+    traitsLeaf(silverMintId, SILVER_TRAITS),
+  ];
+});
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: merkleRoot(traitLeaves),
+      traitRules: [
+        {
+          matchAll: true,
+          traits: [
+            { traitType: "background", value: "gold" },
+            { traitType: "eyes", value: "laser" },
+          ],
+        },
+      ],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.traitRulesRoot).toEqual(merkleRoot(traitLeaves));
+  expect(pool.parsed.traitRules.length).toBe(1);
+});
+
+test("Stake without trait proof fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        { mintId: goldMintId },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with traits not matching rules fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        {
+          mintId: silverMintId,
+          traitProof: {
+            traits: SILVER_TRAITS,
+            proof: merkleProof(traitLeaves, 1),
+          },
+        },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with claimed traits not in root fail", async () => {
+  await expect(
+    executeTransactions(
+      provider.connection,
+      await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+        {
+          mintId: silverMintId,
+          traitProof: {
+            traits: GOLD_TRAITS,
+            proof: merkleProof(traitLeaves, 1),
+          },
+        },
+      ]),
+      provider.wallet,
+      {
+        errorHandler: (e) => {
+          throw e;
+        },
+      }
+    )
+  ).rejects.toThrow();
+});
+
+test("Stake with matching traits", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      {
+        mintId: goldMintId,
+        traitProof: {
+          traits: GOLD_TRAITS,
+          proof: merkleProof(traitLeaves, 0),
+        },
+      },
+    ]),
+    provider.wallet
+  );
+
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    findStakeEntryId(stakePoolId, goldMintId),
+    "stakeEntry"
+  );
+  expect(entry.parsed.lastStaker.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
@@ -0,0 +1,268 @@
+import { beforeAll, expect, test } from "@jest/globals";
+import type { PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import type { SolanaProvider } from "@solana-nft-programs/common";
+import {
+  executeTransaction,
+  executeTransactions,
+} from "@solana-nft-programs/common";
+import BN from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findStakeEntryId,
+  findStakePoolId,
+  findTeamId,
+  rewardsCenterProgram,
+  SOL_PAYMENT_INFO,
+  stake,
+} from "../../sdk";
+import { getTestProvider } from "../../tools/utils";
+import { createMasterEditionTx } from "../utils";
+
+const stakePoolIdentifier = `test-${Math.random()}`;
+let provider: SolanaProvider;
+let mintId: PublicKey;
+const teamAuthority = Keypair.generate();
+
+beforeAll(async () => {
+  provider = await getTestProvider();
+  const mintKeypair = Keypair.generate();
+  mintId = mintKeypair.publicKey;
+  await executeTransaction(
+    provider.connection,
+    await createMasterEditionTx(
+      provider.connection,
+      mintKeypair.publicKey,
+      provider.wallet.publicKey
+    ),
+    provider.wallet,
+    { signers: [mintKeypair] }
+  );
+});
+
+const closeTeamTx = async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const ix = await program.methods
+    .closeTeam()
+    .accounts({
+      team: findTeamId(findStakePoolId(stakePoolIdentifier)),
+      authority: teamAuthority.publicKey,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Init pool", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const tx = new Transaction();
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .initPool({
+      identifier: stakePoolIdentifier,
+      allowedCollections: [],
+      allowedCreators: [],
+      requiresAuthorization: false,
+      authority: provider.wallet.publicKey,
+      resetOnUnstake: false,
+      cooldownSeconds: null,
+      minStakeSeconds: null,
+      endDate: null,
+      stakePaymentInfo: SOL_PAYMENT_INFO,
+      unstakePaymentInfo: SOL_PAYMENT_INFO,
+      passMint: null,
+      feeExemptMint: null,
+      feeExemptBoostAndClaim: false,
+      allowedMintsRoot: null,
+      traitRulesRoot: null,
+      traitRules: [],
+      permitSigner: null,
+      maxStaked: null,
+      maxStakedPerWallet: null,
+      lockTiers: [],
+      loyaltyRamp: null,
+      boostWindows: [],
+      stakeSetRules: [],
+      stakeSecondsDecay: null,
+      warmupSeconds: null,
+      accrueDuringCooldown: false,
+      swapStakeBasisPoints: null,
+    })
+    .accounts({
+      stakePool: stakePoolId,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  tx.add(ix);
+  await executeTransaction(provider.connection, tx, provider.wallet);
+  const pool = await fetchIdlAccount(
+    provider.connection,
+    stakePoolId,
+    "stakePool"
+  );
+  expect(pool.parsed.authority.toString()).toBe(
+    provider.wallet.publicKey.toString()
+  );
+});
+
+test("Init team", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const teamId = findTeamId(stakePoolId);
+  const ix = await program.methods
+    .initTeam(new BN(0))
+    .accounts({
+      team: teamId,
+      stakePool: stakePoolId,
+      authority: teamAuthority.publicKey,
+      payer: provider.wallet.publicKey,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet,
+    { signers: [teamAuthority] }
+  );
+  const team = await fetchIdlAccount(provider.connection, teamId, "team");
+  expect(team.parsed.pool.toString()).toBe(stakePoolId.toString());
+  expect(team.parsed.authority.toString()).toBe(
+    teamAuthority.publicKey.toString()
+  );
+  expect(team.parsed.memberCount).toBe(0);
+});
+
+const joinTeamTx = async (authority: PublicKey) => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const ix = await program.methods
+    .joinTeam()
+    .accounts({
+      team: findTeamId(stakePoolId),
+      stakePool: stakePoolId,
+      stakeEntry: findStakeEntryId(stakePoolId, mintId),
+      user: provider.wallet.publicKey,
+      authority,
+    })
+    .instruction();
+  return new Transaction().add(ix);
+};
+
+test("Stake and join team without team authority fail", async () => {
+  await executeTransactions(
+    provider.connection,
+    await stake(provider.connection, provider.wallet, stakePoolIdentifier, [
+      { mintId },
+    ]),
+    provider.wallet
+  );
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await joinTeamTx(provider.wallet.publicKey),
+      provider.wallet,
+      { silent: true }
+    )
+  ).rejects.toThrow();
+});
+
+test("Join team", async () => {
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const teamId = findTeamId(stakePoolId);
+  await executeTransaction(
+    provider.connection,
+    await joinTeamTx(teamAuthority.publicKey),
+    provider.wallet,
+    { signers: [teamAuthority] }
+  );
+  const team = await fetchIdlAccount(provider.connection, teamId, "team");
+  expect(team.parsed.memberCount).toBe(1);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.team?.toString()).toBe(teamId.toString());
+});
+
+test("Close team with members fail", async () => {
+  await expect(
+    executeTransaction(
+      provider.connection,
+      await closeTeamTx(),
+      provider.wallet,
+      { silent: true, signers: [teamAuthority] }
+    )
+  ).rejects.toThrow();
+});
+
+test("Sync team stake seconds", async () => {
+  await new Promise((r) => setTimeout(r, 2000));
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const teamId = findTeamId(stakePoolId);
+  const ix = await program.methods
+    .syncTeamStakeSeconds()
+    .accounts({
+      team: teamId,
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const team = await fetchIdlAccount(provider.connection, teamId, "team");
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(Number(team.parsed.totalStakeSeconds)).toBeGreaterThan(0);
+  expect(entry.parsed.teamStakeSecondsCheckpoint.toString()).toBe(
+    entry.parsed.totalStakeSeconds.toString()
+  );
+});
+
+test("Leave and close team", async () => {
+  const program = rewardsCenterProgram(provider.connection, provider.wallet);
+  const stakePoolId = findStakePoolId(stakePoolIdentifier);
+  const stakeEntryId = findStakeEntryId(stakePoolId, mintId);
+  const teamId = findTeamId(stakePoolId);
+  const ix = await program.methods
+    .leaveTeam()
+    .accounts({
+      team: teamId,
+      stakePool: stakePoolId,
+      stakeEntry: stakeEntryId,
+      user: provider.wallet.publicKey,
+    })
+    .instruction();
+  await executeTransaction(
+    provider.connection,
+    new Transaction().add(ix),
+    provider.wallet
+  );
+  const team = await fetchIdlAccount(provider.connection, teamId, "team");
+  expect(team.parsed.memberCount).toBe(0);
+  const entry = await fetchIdlAccount(
+    provider.connection,
+    stakeEntryId,
+    "stakeEntry"
+  );
+  expect(entry.parsed.team).toBeNull();
+
+  await executeTransaction(
+    provider.connection,
+    await closeTeamTx(),
+    provider.wallet,
+    { signers: [teamAuthority] }
+  );
+  expect(await provider.connection.getAccountInfo(teamId)).toBeNull();
+});